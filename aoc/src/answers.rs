@@ -0,0 +1,135 @@
+use anyhow::{Context, Result};
+use common::Part;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// `answers.toml` in the workspace root.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.toml")
+}
+
+/// Identifies an input by its 64 bit FNV-1a hash, which is stable across platforms
+/// and Rust versions.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// A known correct answer to one part of a day for one input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    /// [`input_hash`] of the input the answer belongs to.
+    pub input: String,
+    pub answer: String,
+}
+
+/// Outcome of comparing an answer with the known correct one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// The database of known correct answers.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    /// Loads the answers from `path`, starting out empty if it does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text)
+                .with_context(|| format!("could not parse answers '{}'", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => {
+                Err(err).with_context(|| format!("could not read answers '{}'", path.display()))
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("could not write answers '{}'", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|answer| {
+                answer.day == day && answer.part == u8::from(part) && answer.input == input
+            })
+            .map(|answer| answer.answer.as_str())
+    }
+
+    pub fn check(&self, day: u8, part: Part, input: &str, actual: &str) -> Verdict {
+        match self.get(day, part, input) {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Records `answer`, replacing a previous answer for the same day, part and input.
+    pub fn insert(&mut self, day: u8, part: Part, input: &str, answer: &str) {
+        let answer = Answer {
+            day,
+            part: part.into(),
+            input: input.to_string(),
+            answer: answer.to_string(),
+        };
+        self.answers.retain(|known| {
+            (known.day, known.part, &known.input) != (answer.day, answer.part, &answer.input)
+        });
+        self.answers.push(answer);
+        self.answers
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_hash_is_fnv1a() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn answers_are_kept_per_input() {
+        let mut answers = Answers::default();
+        answers.insert(7, Part::Two, "aaaa", "5905");
+        answers.insert(7, Part::Two, "bbbb", "249781879");
+        answers.insert(7, Part::Two, "aaaa", "5906");
+
+        let answers: Answers = toml::from_str(&toml::to_string(&answers).unwrap()).unwrap();
+
+        assert_eq!(answers.check(7, Part::Two, "aaaa", "5906"), Verdict::Pass);
+        assert_eq!(
+            answers.check(7, Part::Two, "bbbb", "1"),
+            Verdict::Fail {
+                expected: "249781879".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(7, Part::One, "aaaa", "6440"),
+            Verdict::Unknown
+        );
+    }
+}
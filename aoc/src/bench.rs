@@ -0,0 +1,158 @@
+use crate::days::Day;
+use anyhow::{Context, Result};
+use common::{Part, Solved};
+use serde::Serialize;
+use std::{fmt::Write, time::Duration};
+
+/// Spread of the durations of one phase over all iterations, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Returns `None` for an empty sample.
+    fn of(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        let nanos = |duration: &Duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        Some(Self {
+            min_ns: nanos(samples.first()?),
+            median_ns: nanos(&samples[samples.len() / 2]),
+            max_ns: nanos(samples.last()?),
+        })
+    }
+}
+
+/// Timings of a single day; parts that are not solved or not selected have none.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl DayBench {
+    fn phases(&self) -> impl Iterator<Item = (&'static str, Stats)> {
+        [
+            ("parse", Some(self.parse)),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+        .into_iter()
+        .filter_map(|(phase, stats)| Some((phase, stats?)))
+    }
+}
+
+/// Solves `input` `iterations` times and collects the timings of every phase.
+pub fn bench(day: &Day, input: &str, parts: &[Part], iterations: usize) -> Result<DayBench> {
+    let mut runs: Vec<Solved> = Vec::with_capacity(iterations);
+    for _ in 0..iterations.max(1) {
+        runs.push((day.solve)(input, parts).with_context(|| format!("Day {}", day.day))?);
+    }
+    let part_stats = |part| {
+        Stats::of(
+            runs.iter()
+                .flat_map(|run| &run.parts)
+                .filter(|solved| solved.part == part && solved.answer.is_some())
+                .map(|solved| solved.duration)
+                .collect(),
+        )
+    };
+    Ok(DayBench {
+        day: day.day,
+        iterations: runs.len(),
+        parse: Stats::of(runs.iter().map(|run| run.parse).collect())
+            .expect("there is at least one run"),
+        part1: part_stats(Part::One),
+        part2: part_stats(Part::Two),
+    })
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+/// One line per day and phase, for the terminal.
+pub fn to_text(benches: &[DayBench]) -> String {
+    let mut text = String::new();
+    for bench in benches {
+        for (phase, stats) in bench.phases() {
+            let _ = writeln!(
+                text,
+                "Day {:>2} {phase:<6}  min {:>10}  median {:>10}  max {:>10}",
+                bench.day,
+                format_ns(stats.min_ns),
+                format_ns(stats.median_ns),
+                format_ns(stats.max_ns),
+            );
+        }
+    }
+    text
+}
+
+pub fn to_markdown(benches: &[DayBench]) -> String {
+    let mut markdown = String::from(
+        "| Day | Phase | Iterations | Min | Median | Max |\n\
+         |----:|:------|-----------:|----:|-------:|----:|\n",
+    );
+    for bench in benches {
+        for (phase, stats) in bench.phases() {
+            let _ = writeln!(
+                markdown,
+                "| {} | {phase} | {} | {} | {} | {} |",
+                bench.day,
+                bench.iterations,
+                format_ns(stats.min_ns),
+                format_ns(stats.median_ns),
+                format_ns(stats.max_ns),
+            );
+        }
+    }
+    markdown
+}
+
+pub fn to_json(benches: &[DayBench]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(benches)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_samples() {
+        let samples = [30, 10, 20, 50, 40].map(Duration::from_nanos).to_vec();
+        assert_eq!(
+            Stats::of(samples),
+            Some(Stats {
+                min_ns: 10,
+                median_ns: 30,
+                max_ns: 50
+            })
+        );
+        assert_eq!(Stats::of(Vec::new()), None);
+    }
+
+    #[test]
+    fn markdown_skips_missing_phases() {
+        let stats = Stats {
+            min_ns: 1_000,
+            median_ns: 2_000,
+            max_ns: 3_000,
+        };
+        let bench = DayBench {
+            day: 3,
+            iterations: 5,
+            parse: stats,
+            part1: Some(stats),
+            part2: None,
+        };
+        let markdown = to_markdown(&[bench]);
+        assert_eq!(markdown.lines().count(), 4);
+        assert!(markdown.ends_with("| 3 | part 1 | 5 | 1.00µs | 2.00µs | 3.00µs |\n"));
+    }
+}
@@ -0,0 +1,31 @@
+use common::{Part, Result, Solution, Solved};
+
+/// Parses the input and answers the requested parts; see [`common::solve`].
+type SolveFn = fn(&str, &[Part]) -> Result<Solved>;
+
+/// Entry point of a single day's solver.
+pub struct Day {
+    pub day: u8,
+    pub solve: SolveFn,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: common::solve::<S>,
+        }
+    }
+}
+
+pub static DAYS: [Day; 9] = [
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+    Day::of::<day7::Day7>(),
+    Day::of::<day8::Day8>(),
+    Day::of::<day9::Day9>(),
+];
@@ -0,0 +1,288 @@
+use answers::{Answers, Verdict};
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use common::{Format, InputSource, Inputs, Part, PartAnswer, Reporter};
+use std::{fs, ops::RangeInclusive, path::PathBuf, process::ExitCode, str::FromStr};
+use store::{HttpFetcher, InputStore};
+
+mod answers;
+mod bench;
+mod days;
+mod store;
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code 2023 solutions")]
+struct Cli {
+    /// Directory holding one `dayN/input.txt` per day [default: $AOC_INPUTS or the workspace root]
+    #[arg(long, global = true)]
+    inputs_dir: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers of one or more days
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// Print the answers as `text`, JSON lines (`json`) or `csv`
+        #[arg(long, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Rerun the solvers and compare their answers with the known correct ones
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+        /// File with the known correct answers [default: answers.toml in the workspace root]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Store the answers of parts without a known answer
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time parsing and solving every part of one or more days
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// How often to solve every day
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Also write the results as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Also write the results as a Markdown table to this file
+        #[arg(long)]
+        markdown: Option<PathBuf>,
+    },
+    /// Download the inputs of one or more days into the input cache
+    ///
+    /// Needs the session cookie in $AOC_SESSION or ~/.config/aoc/session.
+    /// Inputs already in the cache are never downloaded again.
+    Fetch {
+        /// A single day (`5`), an inclusive range (`3-7`) or `all`
+        days: Days,
+    },
+}
+
+/// The days, parts and inputs to run.
+#[derive(Args)]
+struct Selection {
+    /// A single day (`5`), an inclusive range (`3-7`) or `all`
+    days: Days,
+    /// Only run the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the input from this file instead, `-` for stdin; needs a single day
+    #[arg(short, long, conflicts_with = "fetch")]
+    input: Option<String>,
+    /// Read the inputs from the input cache, fetching missing ones first
+    #[arg(long)]
+    fetch: bool,
+}
+
+#[derive(Clone, Debug)]
+struct Days(RangeInclusive<u8>);
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |s: &str| match s.trim().parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(day),
+            _ => Err(format!("'{s}' is not a day between 1 and 25")),
+        };
+        if s == "all" {
+            return Ok(Self(1..=25));
+        }
+        match s.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("'{s}' is an empty range of days"));
+                }
+                Ok(Self(start..=end))
+            }
+            None => parse_day(s).map(|day| Self(day..=day)),
+        }
+    }
+}
+
+impl Days {
+    fn select(&self) -> Result<Vec<&'static days::Day>> {
+        let selected: Vec<_> = days::DAYS
+            .iter()
+            .filter(|day| self.0.contains(&day.day))
+            .collect();
+        if selected.is_empty() {
+            bail!("no solutions for days {:?}", self.0);
+        }
+        Ok(selected)
+    }
+}
+
+impl Selection {
+    fn parts(&self) -> &'static [Part] {
+        match self.part {
+            Some(1) => &[Part::One],
+            Some(_) => &[Part::Two],
+            None => &Part::ALL,
+        }
+    }
+
+    /// Calls `f` with every selected day and its input.
+    fn for_each_input(
+        &self,
+        inputs: &Inputs,
+        mut f: impl FnMut(&days::Day, &str) -> Result<()>,
+    ) -> Result<()> {
+        let selected = self.days.select()?;
+        let source = self
+            .input
+            .as_deref()
+            .map_or(InputSource::Default, InputSource::from);
+        if source != InputSource::Default && selected.len() > 1 {
+            bail!("--input can only be used with a single day");
+        }
+        let store = if self.fetch {
+            Some(input_store()?)
+        } else {
+            None
+        };
+        for day in selected {
+            let input = match &store {
+                Some(store) => store.get(day.day)?,
+                None => inputs
+                    .read(day.day, &source)
+                    .with_context(|| format!("Day {}", day.day))?,
+            };
+            f(day, &input)?;
+        }
+        Ok(())
+    }
+}
+
+fn input_store() -> Result<InputStore<HttpFetcher>> {
+    Ok(InputStore::new(
+        store::default_cache_dir()?,
+        store::YEAR,
+        HttpFetcher::from_env(),
+    ))
+}
+
+fn run(inputs: &Inputs, selection: &Selection, format: Format) -> Result<()> {
+    let mut reporter = Reporter::stdout(format);
+    selection.for_each_input(inputs, |day, input| {
+        let solved =
+            (day.solve)(input, selection.parts()).with_context(|| format!("Day {}", day.day))?;
+        for answer in &solved.parts {
+            reporter.report(day.day, answer)?;
+        }
+        Ok(())
+    })
+}
+
+/// Returns whether every answer matched or was unknown.
+fn verify(
+    inputs: &Inputs,
+    selection: &Selection,
+    path: Option<PathBuf>,
+    record: bool,
+) -> Result<bool> {
+    let path = path.unwrap_or_else(answers::default_path);
+    let mut answers = Answers::load(&path)?;
+    let mut all_passed = true;
+    let mut recorded = 0;
+    selection.for_each_input(inputs, |day, input| {
+        let hash = answers::input_hash(input);
+        let solved = match (day.solve)(input, selection.parts()) {
+            Ok(solved) => solved,
+            Err(err) => {
+                println!("Day {}: FAIL\n  error: {err:#}", day.day);
+                all_passed = false;
+                return Ok(());
+            }
+        };
+        for PartAnswer { part, answer, .. } in solved.parts {
+            let Some(actual) = answer else { continue };
+            match answers.check(day.day, part, &hash, &actual) {
+                Verdict::Pass => println!("Day {} Part {part}: PASS", day.day),
+                Verdict::Fail { expected } => {
+                    println!("Day {} Part {part}: FAIL", day.day);
+                    println!("  - {expected}\n  + {actual}");
+                    all_passed = false;
+                }
+                Verdict::Unknown if record => {
+                    println!("Day {} Part {part}: UNKNOWN, recorded {actual}", day.day);
+                    answers.insert(day.day, part, &hash, &actual);
+                    recorded += 1;
+                }
+                Verdict::Unknown => println!("Day {} Part {part}: UNKNOWN ({actual})", day.day),
+            }
+        }
+        Ok(())
+    })?;
+    if recorded > 0 {
+        answers.save(&path)?;
+    }
+    Ok(all_passed)
+}
+
+fn bench(
+    inputs: &Inputs,
+    selection: &Selection,
+    iterations: usize,
+    json: Option<PathBuf>,
+    markdown: Option<PathBuf>,
+) -> Result<()> {
+    let mut benches = Vec::new();
+    selection.for_each_input(inputs, |day, input| {
+        let bench = bench::bench(day, input, selection.parts(), iterations)?;
+        print!("{}", bench::to_text(std::slice::from_ref(&bench)));
+        benches.push(bench);
+        Ok(())
+    })?;
+    if let Some(path) = json {
+        fs::write(&path, bench::to_json(&benches)?)
+            .with_context(|| format!("could not write '{}'", path.display()))?;
+    }
+    if let Some(path) = markdown {
+        fs::write(&path, bench::to_markdown(&benches))
+            .with_context(|| format!("could not write '{}'", path.display()))?;
+    }
+    Ok(())
+}
+
+fn fetch(selection: Days) -> Result<()> {
+    let store = input_store()?;
+    for day in selection.select()? {
+        store.get(day.day)?;
+        println!("Day {}: {}", day.day, store.path(day.day).display());
+    }
+    Ok(())
+}
+
+fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+    let inputs = cli.inputs_dir.map_or_else(Inputs::from_env, Inputs::new);
+    match cli.command {
+        Command::Run { selection, format } => run(&inputs, &selection, format)?,
+        Command::Verify {
+            selection,
+            answers,
+            record,
+        } => {
+            if !verify(&inputs, &selection, answers, record)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Bench {
+            selection,
+            iterations,
+            json,
+            markdown,
+        } => bench(&inputs, &selection, iterations, json, markdown)?,
+        Command::Fetch { days } => fetch(days)?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,307 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    env, fs,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable holding the adventofcode.com session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding [`HttpFetcher::BASE_URL`], e.g. for a local mirror.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Environment variable overriding the directory the fetched inputs are cached in.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE";
+
+pub const YEAR: u16 = 2023;
+
+/// Downloads the puzzle input of a single day.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String>;
+}
+
+/// Fetches inputs from adventofcode.com, or any server with the same URL layout.
+pub struct HttpFetcher {
+    base_url: String,
+    /// Looked up on every fetch when `None`, so cached inputs never need one.
+    session: Option<String>,
+}
+
+impl HttpFetcher {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+
+    /// Without a `session` it is looked up like [`HttpFetcher::from_env`] does.
+    pub fn new(session: Option<String>) -> Self {
+        Self {
+            base_url: Self::BASE_URL.to_string(),
+            session,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Takes the session cookie from `$AOC_SESSION` or, failing that, from the
+    /// `session` file in the config directory (`~/.config/aoc/session`). Both
+    /// are only read once an input is actually fetched.
+    pub fn from_env() -> Self {
+        let fetcher = Self::new(None);
+        match env::var(BASE_URL_VAR) {
+            Ok(base_url) => fetcher.with_base_url(base_url),
+            Err(_) => fetcher,
+        }
+    }
+
+    fn session_from_env() -> Result<String> {
+        if let Ok(session) = env::var(SESSION_VAR) {
+            return Ok(session.trim().to_string());
+        }
+        let Some(path) = config_dir().map(|dir| dir.join("session")) else {
+            bail!("${SESSION_VAR} is not set and there is no config directory");
+        };
+        let session = fs::read_to_string(&path).with_context(|| {
+            format!(
+                "${SESSION_VAR} is not set and '{}' could not be read",
+                path.display()
+            )
+        })?;
+        Ok(session.trim().to_string())
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let session = match &self.session {
+            Some(session) => session.clone(),
+            None => Self::session_from_env().with_context(|| format!("Day {day}"))?,
+        };
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set(
+                "User-Agent",
+                "github.com/HyperSuperMetaCtrl/AOC2023 input cache",
+            )
+            .call()
+            .with_context(|| format!("Day {day}: could not fetch {url}"))?;
+        let mut input = String::new();
+        response
+            .into_reader()
+            .read_to_string(&mut input)
+            .with_context(|| format!("Day {day}: could not read response from {url}"))?;
+        Ok(input)
+    }
+}
+
+/// On-disk cache of puzzle inputs that fetches every input at most once.
+///
+/// Inputs are stored as `<dir>/<year>/dayN/input.txt`, so `<dir>/<year>` can be
+/// used as an inputs directory as well.
+pub struct InputStore<F> {
+    dir: PathBuf,
+    year: u16,
+    fetcher: F,
+}
+
+impl<F: Fetcher> InputStore<F> {
+    pub fn new(dir: impl Into<PathBuf>, year: u16, fetcher: F) -> Self {
+        Self {
+            dir: dir.into(),
+            year,
+            fetcher,
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir
+            .join(self.year.to_string())
+            .join(format!("day{day}"))
+            .join("input.txt")
+    }
+
+    /// Returns the cached input of `day`, fetching and caching it first if needed.
+    pub fn get(&self, day: u8) -> Result<String> {
+        let path = self.path(day);
+        if path.exists() {
+            return fs::read_to_string(&path)
+                .with_context(|| format!("Day {day}: could not read '{}'", path.display()));
+        }
+        let input = self.fetcher.fetch(self.year, day)?;
+        write_atomically(&path, &input)
+            .with_context(|| format!("Day {day}: could not cache input in '{}'", path.display()))?;
+        Ok(input)
+    }
+}
+
+/// Uses `$AOC_CACHE`, `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`.
+pub fn default_cache_dir() -> Result<PathBuf> {
+    if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+        return Ok(PathBuf::from(dir));
+    }
+    xdg_dir("XDG_CACHE_HOME", ".cache")
+        .map(|dir| dir.join("aoc"))
+        .context("could not determine the cache directory, set $AOC_CACHE")
+}
+
+fn config_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_CONFIG_HOME", ".config").map(|dir| dir.join("aoc"))
+}
+
+fn xdg_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    env::var_os(var)
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(fallback)))
+}
+
+/// Writes to a temporary file first so an interrupted download never looks cached.
+fn write_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// Minimal stand-in for adventofcode.com that serves `status`/`body` for every
+    /// request and records the request lines and cookies it received.
+    struct StandIn {
+        url: String,
+        requests: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl StandIn {
+        fn start(status: &'static str, body: &'static str) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let seen = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(&stream);
+                    let mut request_line = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+                    let mut cookie = String::new();
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        if header.trim().is_empty() {
+                            break;
+                        }
+                        if let Some((name, value)) = header.split_once(':') {
+                            if name.eq_ignore_ascii_case("cookie") {
+                                cookie = value.trim().to_string();
+                            }
+                        }
+                    }
+                    seen.lock()
+                        .unwrap()
+                        .push((request_line.trim().to_string(), cookie));
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+            Self { url, requests }
+        }
+
+        fn requests(&self) -> Vec<(String, String)> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-store-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_once_and_caches_on_disk() {
+        let server = StandIn::start("200 OK", "1abc2\n");
+        let dir = temp_dir("cache");
+        let store = InputStore::new(
+            &dir,
+            YEAR,
+            HttpFetcher::new(Some("secret".to_string())).with_base_url(&server.url),
+        );
+
+        assert_eq!(store.get(1).unwrap(), "1abc2\n");
+        assert_eq!(store.get(1).unwrap(), "1abc2\n");
+
+        assert_eq!(
+            server.requests(),
+            [(
+                "GET /2023/day/1/input HTTP/1.1".to_string(),
+                "session=secret".to_string()
+            )]
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("2023/day1/input.txt")).unwrap(),
+            "1abc2\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_fetch_is_not_cached() {
+        let server = StandIn::start("400 Bad Request", "Puzzle inputs differ by user.");
+        let dir = temp_dir("failed");
+        let store = InputStore::new(
+            &dir,
+            YEAR,
+            HttpFetcher::new(Some(String::new())).with_base_url(&server.url),
+        );
+
+        let err = store.get(3).unwrap_err();
+
+        assert!(err.to_string().starts_with("Day 3: could not fetch"));
+        assert!(!store.path(3).exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn cached_input_is_never_refetched() {
+        struct Offline;
+        impl Fetcher for Offline {
+            fn fetch(&self, _year: u16, day: u8) -> Result<String> {
+                panic!("tried to fetch day {day}");
+            }
+        }
+        let dir = temp_dir("offline");
+        let store = InputStore::new(&dir, YEAR, Offline);
+        write_atomically(&store.path(9), "0 3 6\n").unwrap();
+
+        assert_eq!(store.get(9).unwrap(), "0 3 6\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn cached_input_needs_no_session() {
+        let dir = temp_dir("no-session");
+        let store = InputStore::new(
+            &dir,
+            YEAR,
+            HttpFetcher::new(None).with_base_url("http://127.0.0.1:9"),
+        );
+        write_atomically(&store.path(9), "0 3 6\n").unwrap();
+
+        assert_eq!(store.get(9).unwrap(), "0 3 6\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
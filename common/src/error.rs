@@ -0,0 +1,188 @@
+use crate::Part;
+use std::{
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Everything that can go wrong while reading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be read; `path` is `None` for stdin and other readers.
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    /// The input does not have the expected format. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        token: String,
+        expected: String,
+    },
+    /// The input parsed fine but describes a puzzle without an answer.
+    Invalid(String),
+    /// The part has no solution yet.
+    Unsolved(Part),
+}
+
+impl Error {
+    /// A parse error at the first token of `at`, which must be a suffix or other
+    /// subslice of `input`. Blanks in front of the token are skipped.
+    ///
+    /// This fits nom, which hands back the input it could not consume.
+    pub fn parse(input: &str, at: &str, expected: impl Into<String>) -> Self {
+        let at = at.trim_start_matches([' ', '\t']);
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .expect("`at` is not part of `input`");
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let column = before[line_start..].chars().count() + 1;
+        Self::Parse {
+            line,
+            column,
+            token: token(&input[offset..]),
+            expected: expected.into(),
+        }
+    }
+
+    /// A parse error where a complete nom parser gave up on `input`.
+    pub fn from_nom(
+        input: &str,
+        err: nom::Err<nom::error::Error<&str>>,
+        expected: impl Into<String>,
+    ) -> Self {
+        match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                Self::parse(input, err.input, expected)
+            }
+            nom::Err::Incomplete(_) => unreachable!("complete parsers only"),
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::Invalid(message.into())
+    }
+
+    /// Names `path` in an I/O error that came from a reader which did not
+    /// know where it was reading from; other errors are left alone.
+    pub fn with_path(self, path: Option<PathBuf>) -> Self {
+        match self {
+            Self::Io { path: None, source } => Self::Io { path, source },
+            err => err,
+        }
+    }
+}
+
+/// The word `at` starts with, or a description of what is there instead.
+fn token(at: &str) -> String {
+    let end = at.find(char::is_whitespace).unwrap_or(at.len());
+    match &at[..end] {
+        "" if at.is_empty() => "end of input".to_string(),
+        "" if at.starts_with('\n') => "end of line".to_string(),
+        "" => format!("{:?}", at.chars().next().unwrap_or_default()),
+        word => format!("'{word}'"),
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io {
+                path: Some(path), ..
+            } => write!(f, "could not read input '{}'", path.display()),
+            Self::Io { path: None, .. } => write!(f, "could not read input"),
+            Self::Parse {
+                line,
+                column,
+                token,
+                expected,
+            } => write!(
+                f,
+                "line {line}, column {column}: found {token}, expected {expected}"
+            ),
+            Self::Invalid(message) => write!(f, "invalid puzzle: {message}"),
+            Self::Unsolved(part) => write!(f, "Part {part} is not solved yet"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_position() {
+        let input = "Game 1: 3 blue\nGame 2: 4 purple, 1 red\n";
+        let at = &input[input.find("purple").unwrap()..];
+        let Error::Parse {
+            line,
+            column,
+            token,
+            ..
+        } = Error::parse(input, at, "a color")
+        else {
+            panic!("not a parse error");
+        };
+        assert_eq!((line, column, token.as_str()), (2, 11, "'purple,'"));
+    }
+
+    #[test]
+    fn io_error_path() {
+        let err = Error::Io {
+            path: None,
+            source: io::Error::new(io::ErrorKind::InvalidData, "not UTF-8"),
+        };
+        assert_eq!(err.to_string(), "could not read input");
+        assert_eq!(
+            err.with_path(Some(PathBuf::from("day1/input.txt")))
+                .to_string(),
+            "could not read input 'day1/input.txt'"
+        );
+        assert!(matches!(
+            Error::invalid("no answer").with_path(Some(PathBuf::from("x"))),
+            Error::Invalid(_)
+        ));
+    }
+
+    #[test]
+    fn parse_error_message() {
+        let input = "1 2\n3";
+        assert_eq!(
+            Error::parse(input, &input[3..], "a number").to_string(),
+            "line 1, column 4: found end of line, expected a number"
+        );
+        assert_eq!(
+            Error::parse(input, &input[5..], "a number").to_string(),
+            "line 2, column 2: found end of input, expected a number"
+        );
+    }
+
+    #[test]
+    fn nom_error_position() {
+        let input = "seeds: 79 x";
+        let err = nom::sequence::preceded(
+            nom::bytes::complete::tag::<_, _, nom::error::Error<&str>>("seeds: 79 "),
+            nom::character::complete::u64,
+        )(input)
+        .unwrap_err();
+        assert_eq!(
+            Error::from_nom(input, err, "a seed").to_string(),
+            "line 1, column 11: found 'x', expected a seed"
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{Error, Result};
+use std::ops::{Index, IndexMut, Range};
+
+/// A position in a [`Grid`] as 0-based `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// Which cells around a position are its neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Left, right, above and below.
+    Four,
+    /// Like [`Connectivity::Four`] and also diagonally.
+    Eight,
+}
+
+impl Connectivity {
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Self::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Self::Eight => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// A horizontal run of cells in one row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub row: usize,
+    pub columns: Range<usize>,
+}
+
+/// A rectangle of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A copy of the grid with `by` rows and columns of `fill` on every side.
+    pub fn padded(&self, by: usize, fill: T) -> Self {
+        let mut padded = Self::new(self.width + 2 * by, self.height + 2 * by, fill);
+        for (row, cells) in self.rows().enumerate() {
+            let start = (row + by) * padded.width + by;
+            padded.cells[start..start + self.width].clone_from_slice(cells);
+        }
+        padded
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid of `cells` given row by row, `None` unless there are exactly
+    /// `width * height` of them.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == width * height).then_some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Pos) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// The neighbors of `pos` that are inside the grid.
+    pub fn neighbors(
+        &self,
+        (row, column): Pos,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = Pos> + '_ {
+        connectivity.offsets().iter().filter_map(move |(dr, dc)| {
+            let pos = (
+                row.checked_add_signed(*dr)?,
+                column.checked_add_signed(*dc)?,
+            );
+            self.contains(pos).then_some(pos)
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` does not accept a width of 0
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {column} is outside the grid");
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index / width, index % width), cell))
+    }
+
+    /// The cells of a rectangle, row by row, leaving out the parts outside
+    /// the grid.
+    pub fn region(
+        &self,
+        rows: Range<usize>,
+        columns: Range<usize>,
+    ) -> impl Iterator<Item = (Pos, &T)> {
+        let rows = rows.start.min(self.height)..rows.end.min(self.height);
+        let columns = columns.start.min(self.width)..columns.end.min(self.width);
+        rows.flat_map(move |row| {
+            columns
+                .clone()
+                .map(move |column| ((row, column), &self[(row, column)]))
+        })
+    }
+
+    /// The longest horizontal runs of cells that match `predicate`, row by row.
+    pub fn runs<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Run> + 'a {
+        self.rows().enumerate().flat_map(move |(row, cells)| {
+            let mut runs = Vec::new();
+            let mut start = None;
+            for (column, cell) in cells.iter().enumerate() {
+                match (predicate(cell), start) {
+                    (true, None) => start = Some(column),
+                    (false, Some(first)) => {
+                        runs.push(Run {
+                            row,
+                            columns: first..column,
+                        });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(first) = start {
+                runs.push(Run {
+                    row,
+                    columns: first..cells.len(),
+                });
+            }
+            runs
+        })
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Reads one row per line, taking the width from the first line. Every
+    /// other line must be just as long.
+    pub fn parse(input: &str) -> Result<Self> {
+        let width = input.lines().next().map_or(0, str::len);
+        if width == 0 {
+            return Err(Error::invalid("the grid is empty"));
+        }
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+        for line in input.lines() {
+            if let Some(offset) = line.find(|c: char| !c.is_ascii()) {
+                return Err(Error::parse(input, &line[offset..], "an ASCII character"));
+            }
+            if line.len() != width {
+                return Err(Error::parse(
+                    input,
+                    &line[width.min(line.len())..],
+                    format!("a row of {width} characters"),
+                ));
+            }
+            cells.extend_from_slice(line.as_bytes());
+            height += 1;
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): Pos) -> &T {
+        assert!(
+            self.contains((row, column)),
+            "({row}, {column}) is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        &self.cells[row * self.width + column]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, column): Pos) -> &mut T {
+        assert!(
+            self.contains((row, column)),
+            "({row}, {column}) is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        &mut self.cells[row * self.width + column]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rows() {
+        let grid = Grid::parse("467..\n...*.\n..35.\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (5, 3));
+        assert_eq!(grid[(1, 3)], b'*');
+        assert_eq!(grid.row(2), b"..35.");
+        assert_eq!(grid.column(0).copied().collect::<Vec<_>>(), b"4..");
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(
+            Grid::parse("467..\n...*\n").unwrap_err().to_string(),
+            "line 2, column 5: found end of line, expected a row of 5 characters"
+        );
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::new(3, 2, 0);
+        let four: Vec<_> = grid.neighbors((0, 0), Connectivity::Four).collect();
+        assert_eq!(four, [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors((1, 1), Connectivity::Eight).count(), 5);
+        let region: Vec<_> = grid.region(1..4, 2..5).map(|(pos, _)| pos).collect();
+        assert_eq!(region, [(1, 2)]);
+    }
+
+    #[test]
+    fn runs_and_padding() {
+        let grid = Grid::parse("12.3\n.45.\n").unwrap();
+        let runs: Vec<_> = grid.runs(u8::is_ascii_digit).collect();
+        assert_eq!(
+            runs,
+            [
+                Run {
+                    row: 0,
+                    columns: 0..2
+                },
+                Run {
+                    row: 0,
+                    columns: 3..4
+                },
+                Run {
+                    row: 1,
+                    columns: 1..3
+                },
+            ]
+        );
+        let padded = grid.padded(1, b'.');
+        assert_eq!((padded.width(), padded.height()), (6, 4));
+        assert_eq!(padded.row(1), b".12.3.");
+        assert_eq!(padded.row(3), b"......");
+    }
+}
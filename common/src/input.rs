@@ -0,0 +1,106 @@
+use crate::{Error, Result};
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding the directory that holds the puzzle inputs.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The day's file inside the inputs directory, see [`Inputs::default_path`].
+    #[default]
+    Default,
+    /// An explicit file.
+    Path(PathBuf),
+    /// Standard input, selected with `-`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Takes the source from the first command line argument, if there is one.
+    pub fn from_args() -> Self {
+        env::args()
+            .nth(1)
+            .map_or(Self::Default, |arg| Self::from(arg.as_str()))
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(s: &str) -> Self {
+        match s {
+            "-" => Self::Stdin,
+            path => Self::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+/// The directory holding one `dayN/input.txt` per day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Uses `$AOC_INPUTS` if set and the workspace root otherwise.
+    pub fn from_env() -> Self {
+        match env::var_os(INPUTS_DIR_VAR) {
+            Some(dir) => Self::new(dir),
+            None => Self::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn default_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day}")).join("input.txt")
+    }
+
+    /// The file the input of `day` is read from, `None` for stdin.
+    pub fn path(&self, day: u8, source: &InputSource) -> Option<PathBuf> {
+        match source {
+            InputSource::Default => Some(self.default_path(day)),
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    /// Reads the input of `day` from `source`.
+    pub fn read(&self, day: u8, source: &InputSource) -> Result<String> {
+        let Some(path) = self.path(day, source) else {
+            let mut input = String::new();
+            return match io::stdin().read_to_string(&mut input) {
+                Ok(_) => Ok(input),
+                Err(source) => Err(Error::Io { path: None, source }),
+            };
+        };
+        fs::read_to_string(&path).map_err(|source| Error::Io {
+            path: Some(path),
+            source,
+        })
+    }
+
+    /// Opens the input of `day` from `source` for reading it bit by bit.
+    pub fn open(&self, day: u8, source: &InputSource) -> Result<Box<dyn BufRead>> {
+        let Some(path) = self.path(day, source) else {
+            return Ok(Box::new(io::stdin().lock()));
+        };
+        match File::open(&path) {
+            Ok(file) => Ok(Box::new(BufReader::new(file))),
+            Err(source) => Err(Error::Io {
+                path: Some(path),
+                source,
+            }),
+        }
+    }
+}
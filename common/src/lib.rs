@@ -0,0 +1,13 @@
+//! Building blocks shared by the daily solutions and the `aoc` runner.
+
+mod error;
+mod grid;
+mod input;
+mod report;
+mod solution;
+
+pub use error::{Error, Result};
+pub use grid::{Connectivity, Grid, Pos, Run};
+pub use input::{InputSource, Inputs, INPUTS_DIR_VAR};
+pub use report::{Format, Reporter};
+pub use solution::{print_answers, solve, Part, PartAnswer, Solution, Solved};
@@ -0,0 +1,179 @@
+use crate::PartAnswer;
+use std::{
+    fmt::{self, Display},
+    io::{self, Write},
+    str::FromStr,
+};
+
+/// How a [`Reporter`] renders answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// `Day 9 Part 1: 114`, one line per part.
+    #[default]
+    Text,
+    /// One JSON object per line with `day`, `part`, `answer` and `duration_ns`.
+    Json,
+    /// A `day,part,answer,duration_ns` header followed by one row per part.
+    Csv,
+}
+
+impl Format {
+    pub const ALL: [Format; 3] = [Format::Text, Format::Json, Format::Csv];
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Csv => "csv",
+        })
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.to_string() == s)
+            .ok_or_else(|| format!("'{s}' is not one of text, json or csv"))
+    }
+}
+
+/// Writes the answers of any number of days in a single [`Format`].
+pub struct Reporter<W> {
+    out: W,
+    format: Format,
+    started: bool,
+}
+
+impl Reporter<io::Stdout> {
+    pub fn stdout(format: Format) -> Self {
+        Self::new(io::stdout(), format)
+    }
+}
+
+impl<W: Write> Reporter<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        Self {
+            out,
+            format,
+            started: false,
+        }
+    }
+
+    /// Writes one part's answer; parts that are not solved have no answer.
+    pub fn report(&mut self, day: u8, answer: &PartAnswer) -> io::Result<()> {
+        let part = answer.part;
+        let duration_ns = answer.duration.as_nanos();
+        match self.format {
+            Format::Text => match &answer.answer {
+                Some(value) => writeln!(self.out, "Day {day} Part {part}: {value}"),
+                None => writeln!(self.out, "Day {day} Part {part}: not solved"),
+            },
+            Format::Json => {
+                let value = answer
+                    .answer
+                    .as_deref()
+                    .map_or("null".to_string(), json_string);
+                writeln!(
+                    self.out,
+                    r#"{{"day":{day},"part":{part},"answer":{value},"duration_ns":{duration_ns}}}"#
+                )
+            }
+            Format::Csv => {
+                if !self.started {
+                    writeln!(self.out, "day,part,answer,duration_ns")?;
+                    self.started = true;
+                }
+                let value = answer.answer.as_deref().map_or(String::new(), csv_field);
+                writeln!(self.out, "{day},{part},{value},{duration_ns}")
+            }
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Quotes the field if it contains a separator, quote or line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+    use std::time::Duration;
+
+    fn answers() -> [PartAnswer; 2] {
+        [
+            PartAnswer {
+                part: Part::One,
+                answer: Some("114".to_string()),
+                duration: Duration::from_nanos(1_500),
+            },
+            PartAnswer {
+                part: Part::Two,
+                answer: None,
+                duration: Duration::from_nanos(20),
+            },
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let mut reporter = Reporter::new(Vec::new(), format);
+        for answer in answers() {
+            reporter.report(9, &answer).unwrap();
+        }
+        String::from_utf8(reporter.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn every_format() {
+        assert_eq!(
+            render(Format::Text),
+            "Day 9 Part 1: 114\nDay 9 Part 2: not solved\n"
+        );
+        assert_eq!(
+            render(Format::Json),
+            "{\"day\":9,\"part\":1,\"answer\":\"114\",\"duration_ns\":1500}\n\
+             {\"day\":9,\"part\":2,\"answer\":null,\"duration_ns\":20}\n"
+        );
+        assert_eq!(
+            render(Format::Csv),
+            "day,part,answer,duration_ns\n9,1,114,1500\n9,2,,20\n"
+        );
+    }
+
+    #[test]
+    fn escapes_answers() {
+        assert_eq!(json_string("a\"b\\c\n"), r#""a\"b\\c\n""#);
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{Error, Format, Reporter, Result};
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+/// A day's puzzle: the input is parsed once and both parts are answered from it.
+///
+/// A part without a solution returns [`Error::Unsolved`] and uses
+/// [`std::convert::Infallible`] as its answer type.
+pub trait Solution {
+    /// The day of December the puzzle was released on.
+    const DAY: u8;
+
+    /// The parsed puzzle input, possibly borrowing from the raw text.
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2>;
+}
+
+/// The answer to one part together with how long it took to compute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartAnswer {
+    pub part: Part,
+    /// The rendered answer, `None` if the part is not solved yet.
+    pub answer: Option<String>,
+    pub duration: Duration,
+}
+
+impl PartAnswer {
+    /// Times `solve` and renders its answer as text; a part that is not
+    /// solved yet yields no answer instead of failing.
+    pub fn timed<T: Display>(part: Part, solve: impl FnOnce() -> Result<T>) -> Result<Self> {
+        let start = Instant::now();
+        let answer = solve();
+        let duration = start.elapsed();
+        let answer = match answer {
+            Ok(answer) => Some(answer.to_string()),
+            Err(Error::Unsolved(_)) => None,
+            Err(err) => return Err(err),
+        };
+        Ok(Self {
+            part,
+            answer,
+            duration,
+        })
+    }
+}
+
+/// Outcome of parsing an input once and solving some of its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub parse: Duration,
+    pub parts: Vec<PartAnswer>,
+}
+
+/// Parses `input` once and solves the requested `parts`, timing every phase and
+/// rendering every answer as text.
+///
+/// Parts that are not solved yet yield no answer instead of failing.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Solved> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+    let mut answers = Vec::with_capacity(parts.len());
+    for &part in parts {
+        answers.push(match part {
+            Part::One => PartAnswer::timed(part, || S::part1(&input))?,
+            Part::Two => PartAnswer::timed(part, || S::part2(&input))?,
+        });
+    }
+    Ok(Solved {
+        parse,
+        parts: answers,
+    })
+}
+
+/// Solves both parts and prints them as [`Format::Text`].
+pub fn print_answers<S: Solution>(input: &str) -> Result<()> {
+    let mut reporter = Reporter::stdout(Format::Text);
+    for part in solve::<S>(input, &Part::ALL)?.parts {
+        // Like `println!`, a closed stdout is not worth recovering from.
+        reporter
+            .report(S::DAY, &part)
+            .expect("could not write to stdout");
+    }
+    Ok(())
+}
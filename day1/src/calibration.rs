@@ -0,0 +1,181 @@
+use crate::{Digit, Scanner};
+use common::{Error, Result};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// What to do with a line in which the scanner finds no digit at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoDigit {
+    /// Leave the line out of the sum.
+    Skip,
+    /// Count the line as a calibration value of 0.
+    ///
+    /// The sum is the same as with [`NoDigit::Skip`], only the audit differs.
+    Zero,
+    /// Fail with a parse error pointing at the line.
+    #[default]
+    Error,
+}
+
+impl NoDigit {
+    pub const ALL: [NoDigit; 3] = [NoDigit::Skip, NoDigit::Zero, NoDigit::Error];
+}
+
+impl Display for NoDigit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Skip => "skip",
+            Self::Zero => "zero",
+            Self::Error => "error",
+        })
+    }
+}
+
+impl FromStr for NoDigit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|policy| policy.to_string() == s)
+            .ok_or_else(|| format!("'{s}' is not one of skip, zero or error"))
+    }
+}
+
+/// How the calibration value of a single line came about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration<'a> {
+    /// The 1-based line number.
+    pub line: usize,
+    pub text: &'a str,
+    /// The first and the last digit, `None` if the line has none.
+    pub digits: Option<(Digit, Digit)>,
+    /// The calibration value after applying the [`NoDigit`] policy, `None` if skipped.
+    pub value: Option<u32>,
+}
+
+impl Calibration<'_> {
+    /// The text a digit was found as, e.g. `7` or `seven`.
+    pub fn token(&self, digit: Digit) -> &str {
+        &self.text[digit.offset..digit.offset + digit.length]
+    }
+}
+
+/// One line of an audit report, with the byte offsets of the chosen digits.
+impl Display for Calibration<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match (self.digits, self.value) {
+            (Some((first, last)), Some(value)) => write!(
+                f,
+                "first '{}' at {}, last '{}' at {} -> {value}",
+                self.token(first),
+                first.offset,
+                self.token(last),
+                last.offset
+            ),
+            (_, Some(value)) => write!(f, "no digit -> {value}"),
+            (_, None) => write!(f, "no digit -> skipped"),
+        }
+    }
+}
+
+/// Error for the line at `index` that contains no digit at all.
+fn no_digit(index: usize, line: &str) -> Error {
+    Error::Parse {
+        line: index + 1,
+        column: 1,
+        token: format!("'{line}'"),
+        expected: "a line with at least one digit".to_string(),
+    }
+}
+
+/// The calibration of the line at `index`.
+pub fn calibrate_line<'a>(
+    index: usize,
+    text: &'a str,
+    scanner: &Scanner,
+    policy: NoDigit,
+) -> Result<Calibration<'a>> {
+    let digits = scanner.first_and_last(text);
+    let value = match (digits, policy) {
+        (Some((first, last)), _) => Some(first.value * 10 + last.value),
+        (None, NoDigit::Skip) => None,
+        (None, NoDigit::Zero) => Some(0),
+        (None, NoDigit::Error) => return Err(no_digit(index, text)),
+    };
+    Ok(Calibration {
+        line: index + 1,
+        text,
+        digits,
+        value,
+    })
+}
+
+/// The calibration of every line, stopping at the first line without a digit
+/// under [`NoDigit::Error`].
+pub fn calibrations<'a>(
+    lines: &'a [&'a str],
+    scanner: &'a Scanner,
+    policy: NoDigit,
+) -> impl Iterator<Item = Result<Calibration<'a>>> + 'a {
+    lines
+        .iter()
+        .enumerate()
+        .map(move |(index, text)| calibrate_line(index, text, scanner, policy))
+}
+
+/// Sums the first and last digit `scanner` finds in every line.
+///
+/// Part 2 in another language is
+/// `calibrate(lines, &Scanner::with_vocabulary(&vocabulary), NoDigit::Error)`.
+/// The sum is a `u64` like the one of [`crate::calibrate_reader`].
+pub fn calibrate(lines: &[&str], scanner: &Scanner, policy: NoDigit) -> Result<u64> {
+    let mut sum = 0;
+    for calibration in calibrations(lines, scanner, policy) {
+        sum += u64::from(calibration?.value.unwrap_or(0));
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vocabulary;
+
+    const LINES: [&str; 3] = ["xtwone3four", "pqrstuvwx", "treb7uchet"];
+
+    fn audit(policy: NoDigit) -> Vec<String> {
+        let scanner = Scanner::with_vocabulary(&Vocabulary::english());
+        calibrations(&LINES, &scanner, policy)
+            .map(|calibration| calibration.map(|calibration| calibration.to_string()))
+            .collect::<Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn audit_reports_the_chosen_tokens() {
+        assert_eq!(
+            audit(NoDigit::Skip),
+            [
+                "line 1: first 'two' at 1, last 'four' at 7 -> 24",
+                "line 2: no digit -> skipped",
+                "line 3: first '7' at 4, last '7' at 4 -> 77",
+            ]
+        );
+        assert_eq!(audit(NoDigit::Zero)[1], "line 2: no digit -> 0");
+    }
+
+    #[test]
+    fn policies() {
+        let scanner = Scanner::numerals();
+        assert_eq!(calibrate(&LINES, &scanner, NoDigit::Skip).unwrap(), 110);
+        assert_eq!(calibrate(&LINES, &scanner, NoDigit::Zero).unwrap(), 110);
+        assert!(matches!(
+            calibrate(&LINES, &scanner, NoDigit::Error),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,112 @@
+//! Day 1: Trebuchet?! Calibration values hidden in lines of text.
+
+use common::{Result, Solution};
+
+mod calibration;
+mod scanner;
+mod stream;
+mod vocabulary;
+
+pub use calibration::{calibrate, calibrate_line, calibrations, Calibration, NoDigit};
+pub use scanner::{Digit, Scanner, NUMERALS};
+pub use stream::{calibrate_reader, calibrate_reader_parallel};
+pub use vocabulary::{Vocabulary, ENGLISH, FRENCH, GERMAN, SPANISH};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<u64> {
+        part2(input)
+    }
+}
+
+/// Sums the first and last digit of every line.
+pub fn part1(lines: &[&str]) -> Result<u64> {
+    calibrate(lines, &Scanner::numerals(), NoDigit::Error)
+}
+
+/// Like [`part1`], but digits may also be spelled out in English.
+pub fn part2(lines: &[&str]) -> Result<u64> {
+    calibrate(
+        lines,
+        &Scanner::with_vocabulary(&Vocabulary::english()),
+        NoDigit::Error,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const EXAMPLE2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day1::part1(&input).unwrap(), 142);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day1::part2(&input).unwrap(), 281);
+    }
+
+    #[test]
+    fn part2_overlapping_words() {
+        // 21 + 82 + 18
+        assert_eq!(part2(&["twone", "eightwo", "oneight"]).unwrap(), 121);
+    }
+
+    #[test]
+    fn other_vocabularies() {
+        let german = Scanner::with_vocabulary(&Vocabulary::builtin("german").unwrap());
+        let french = Scanner::with_vocabulary(&Vocabulary::builtin("french").unwrap());
+        // 24 + 55
+        assert_eq!(
+            calibrate(&["zweiundvierzig", "fünf"], &german, NoDigit::Error).unwrap(),
+            79
+        );
+        // 29 + 14
+        assert_eq!(
+            calibrate(&["deux3neuf", "unquatre"], &french, NoDigit::Error).unwrap(),
+            43
+        );
+    }
+
+    #[test]
+    fn line_without_digit_is_an_error() {
+        let input = Day1::parse("1abc2\npqrstuvwx\n").unwrap();
+        assert_eq!(
+            Day1::part1(&input).unwrap_err().to_string(),
+            "line 2, column 1: found 'pqrstuvwx', expected a line with at least one digit"
+        );
+    }
+}
@@ -1,89 +1,104 @@
-use anyhow::Result;
-use std::collections::HashMap;
-use std::fs::read_to_string;
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use common::{Format, InputSource, Inputs, Part, PartAnswer, Reporter, Solution};
+use day1::{Day1, NoDigit, Scanner, Vocabulary};
+use std::fs;
 
-fn main() -> Result<()> {
-    let input = read_to_string("input.txt")?;
-    // for every line extract numbers
-    let numbers: Vec<_> = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .filter(|c| c.is_digit(10))
-                .collect::<Vec<char>>()
-        })
-        .collect();
-    // for every line find the first and last digit
-    let numbers: Vec<_> = numbers
-        .into_iter()
-        .map(|number| {
-            number.first().unwrap().to_digit(10).unwrap() * 10
-                + number.last().unwrap().to_digit(10).unwrap()
-        })
-        .collect();
-    // add them all up
-    let sum: u32 = numbers.iter().sum();
-
-    println!("Day 1 Part 1: {sum}");
+#[derive(Parser)]
+#[command(about = "Day 1: Trebuchet?!")]
+struct Args {
+    /// Read the input from this file, `-` for stdin [default: day1/input.txt in the inputs directory]
+    input: Option<String>,
+    /// Digit words of part 2: english, german, french, spanish or a TOML file of `word = digit` pairs
+    #[arg(long, default_value = "english")]
+    vocabulary: String,
+    /// What to do with lines without any digit: skip, zero or error
+    #[arg(long, default_value_t = NoDigit::Error)]
+    no_digit: NoDigit,
+    /// Print which digits were chosen in every line of this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    audit: Option<u8>,
+    /// Only run the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the input line by line instead of all at once, for inputs too large for memory
+    #[arg(long, conflicts_with = "audit")]
+    stream: bool,
+    /// With --stream, calibrate this many lines at a time in parallel
+    #[arg(long, requires = "stream")]
+    chunk_lines: Option<usize>,
+}
 
-    // define a lookup table
-    let lookup_nums = HashMap::from([
-        ("zero", 0),
-        ("one", 1),
-        ("two", 2),
-        ("three", 3),
-        ("four", 4),
-        ("five", 5),
-        ("six", 6),
-        ("seven", 7),
-        ("eight", 8),
-        ("nine", 9),
-    ]);
-    let mut sum = 0;
-    for line in input.lines() {
-        let left_index = line.find(|c: char| c.is_ascii_digit()).unwrap();
-        let right_index = line.rfind(|c: char| c.is_ascii_digit()).unwrap();
-        let mut num = 0;
-        if let Some((_, left)) = find_replacement(&lookup_nums, &line[..left_index]) {
-            num += 10 * left;
-        } else {
-            num += 10 * line.chars().nth(left_index).unwrap().to_digit(10).unwrap();
-        }
-        if let Some((_, right)) = rfind_replacement(&lookup_nums, &line[right_index..]) {
-            num += right;
-        } else {
-            num += line.chars().nth(right_index).unwrap().to_digit(10).unwrap();
-        }
-        sum += num;
+fn vocabulary(name: &str) -> Result<Vocabulary> {
+    if let Some(vocabulary) = Vocabulary::builtin(name) {
+        return Ok(vocabulary);
     }
-    println!("Day 1 Part 2: {sum}");
-    Ok(())
+    let text = fs::read_to_string(name).with_context(|| {
+        format!(
+            "'{name}' is neither one of {} nor a readable file",
+            Vocabulary::BUILTIN.join(", ")
+        )
+    })?;
+    Vocabulary::from_toml(&text).with_context(|| format!("'{name}'"))
 }
 
-fn find_replacement<'a>(
-    lookup: &'a HashMap<&'a str, u32>,
-    substr: &'a str,
-) -> Option<(usize, &'a u32)> {
-    let keys: Vec<_> = lookup.keys().collect();
-    let mut replacements = Vec::new();
-    for key in keys {
-        if let Some(index) = substr.find(key) {
-            replacements.push((index, lookup.get(key).unwrap()))
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let vocabulary = vocabulary(&args.vocabulary)?;
+    let source = args
+        .input
+        .as_deref()
+        .map_or(InputSource::Default, InputSource::from);
+    let scanners = [Scanner::numerals(), Scanner::with_vocabulary(&vocabulary)];
+    let mut reporter = Reporter::stdout(Format::Text);
+    let parts = match args.part {
+        Some(part) => usize::from(part) - 1..usize::from(part),
+        None => 0..2,
+    };
+    if args.stream {
+        if source == InputSource::Stdin && parts.len() > 1 {
+            bail!("stdin can only be streamed once, choose a --part");
+        }
+        for (part, scanner) in Part::ALL
+            .into_iter()
+            .zip(&scanners)
+            .skip(parts.start)
+            .take(parts.len())
+        {
+            let inputs = Inputs::from_env();
+            let reader = inputs.open(Day1::DAY, &source).context("Day 1")?;
+            let answer = PartAnswer::timed(part, || {
+                match args.chunk_lines {
+                    Some(chunk_lines) => {
+                        day1::calibrate_reader_parallel(reader, scanner, args.no_digit, chunk_lines)
+                    }
+                    None => day1::calibrate_reader(reader, scanner, args.no_digit),
+                }
+                // the reader does not know the file it reads
+                .map_err(|err| err.with_path(inputs.path(Day1::DAY, &source)))
+            });
+            reporter.report(Day1::DAY, &answer.context("Day 1")?)?;
         }
+        return Ok(());
     }
-    replacements.into_iter().min_by(|x, y| x.0.cmp(&y.0))
-}
-
-fn rfind_replacement<'a>(
-    lookup: &'a HashMap<&'a str, u32>,
-    substr: &'a str,
-) -> Option<(usize, &'a u32)> {
-    let keys: Vec<_> = lookup.keys().collect();
-    let mut replacements = Vec::new();
-    for key in keys {
-        if let Some(index) = substr.rfind(key) {
-            replacements.push((index, lookup.get(key).unwrap()))
+    let input = Inputs::from_env()
+        .read(Day1::DAY, &source)
+        .context("Day 1")?;
+    let lines = Day1::parse(&input).context("Day 1")?;
+    if let Some(part) = args.audit {
+        let scanner = &scanners[usize::from(part) - 1];
+        for calibration in day1::calibrations(&lines, scanner, args.no_digit) {
+            println!("{}", calibration.context("Day 1")?);
         }
     }
-    replacements.into_iter().max_by(|x, y| x.0.cmp(&y.0))
+    for (part, scanner) in Part::ALL
+        .into_iter()
+        .zip(&scanners)
+        .skip(parts.start)
+        .take(parts.len())
+    {
+        let answer = PartAnswer::timed(part, || day1::calibrate(&lines, scanner, args.no_digit));
+        reporter.report(Day1::DAY, &answer.context("Day 1")?)?;
+    }
+    Ok(())
 }
@@ -0,0 +1,171 @@
+use crate::Vocabulary;
+use std::collections::VecDeque;
+
+/// The ASCII digits `0` to `9`.
+pub const NUMERALS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// A digit found in a line, `length` bytes starting at the byte `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Digit {
+    pub offset: usize,
+    pub length: usize,
+    pub value: u32,
+}
+
+/// Aho-Corasick automaton that finds every occurrence of a set of patterns in
+/// a single pass, including overlapping ones like `twone`.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    /// `transitions[state][byte]`, with the failure links already folded in.
+    transitions: Vec<[usize; 256]>,
+    /// The `(length, value)` of every pattern that ends in a state.
+    outputs: Vec<Vec<(usize, u32)>>,
+}
+
+impl Scanner {
+    /// Builds the automaton for `patterns`; empty patterns never match.
+    pub fn new<'a>(patterns: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        let mut transitions = vec![[0; 256]];
+        let mut outputs = vec![Vec::new()];
+        // The trie first; the root is never a child, so 0 means "no edge" for now.
+        for (pattern, value) in patterns {
+            if pattern.is_empty() {
+                continue;
+            }
+            let mut state = 0;
+            for byte in pattern.bytes() {
+                state = match transitions[state][usize::from(byte)] {
+                    0 => {
+                        transitions.push([0; 256]);
+                        outputs.push(Vec::new());
+                        transitions[state][usize::from(byte)] = transitions.len() - 1;
+                        transitions.len() - 1
+                    }
+                    next => next,
+                };
+            }
+            outputs[state].push((pattern.len(), value));
+        }
+        // Then the failure links, breadth first so every state's failure state
+        // is complete before the state itself.
+        let mut fail = vec![0; transitions.len()];
+        let mut queue: VecDeque<usize> = transitions[0]
+            .iter()
+            .copied()
+            .filter(|&child| child != 0)
+            .collect();
+        while let Some(state) = queue.pop_front() {
+            let inherited = outputs[fail[state]].clone();
+            outputs[state].extend(inherited);
+            let failure = transitions[fail[state]];
+            for (byte, next) in transitions[state].iter_mut().enumerate() {
+                match *next {
+                    0 => *next = failure[byte],
+                    child => {
+                        fail[child] = failure[byte];
+                        queue.push_back(child);
+                    }
+                }
+            }
+        }
+        Self {
+            transitions,
+            outputs,
+        }
+    }
+
+    /// Finds the ASCII digits only, as in part 1.
+    pub fn numerals() -> Self {
+        Self::new(NUMERALS)
+    }
+
+    /// Finds the ASCII digits and the words of `vocabulary`, as in part 2.
+    pub fn with_vocabulary(vocabulary: &Vocabulary) -> Self {
+        Self::new(NUMERALS.into_iter().chain(vocabulary.words()))
+    }
+
+    /// Every occurrence of a pattern in `line`, ordered by where it ends.
+    pub fn digits<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Digit> + 'a {
+        let mut state = 0;
+        line.bytes().enumerate().flat_map(move |(end, byte)| {
+            state = self.transitions[state][usize::from(byte)];
+            self.outputs[state]
+                .iter()
+                .map(move |&(length, value)| Digit {
+                    offset: end + 1 - length,
+                    length,
+                    value,
+                })
+        })
+    }
+
+    /// The first and the last digit of `line`, `None` if there is none.
+    pub fn first_and_last(&self, line: &str) -> Option<(Digit, Digit)> {
+        let mut digits = self.digits(line);
+        let first = digits.next()?;
+        Some(digits.fold((first, first), |(first, last), digit| {
+            (
+                if digit.offset < first.offset {
+                    digit
+                } else {
+                    first
+                },
+                if digit.offset >= last.offset {
+                    digit
+                } else {
+                    last
+                },
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(scanner: &Scanner, line: &str) -> Vec<(usize, u32)> {
+        scanner
+            .digits(line)
+            .map(|digit| (digit.offset, digit.value))
+            .collect()
+    }
+
+    #[test]
+    fn finds_overlapping_words() {
+        let scanner = Scanner::with_vocabulary(&Vocabulary::english());
+        assert_eq!(values(&scanner, "twone"), [(0, 2), (2, 1)]);
+        assert_eq!(values(&scanner, "eightwo"), [(0, 8), (4, 2)]);
+        assert_eq!(values(&scanner, "x7oneight"), [(1, 7), (2, 1), (4, 8)]);
+    }
+
+    #[test]
+    fn numerals_ignore_words() {
+        let scanner = Scanner::numerals();
+        assert_eq!(values(&scanner, "a1b2c3one"), [(1, 1), (3, 2), (5, 3)]);
+        assert_eq!(
+            scanner
+                .first_and_last("treb7uchet")
+                .map(|(first, last)| (first.value, last.value)),
+            Some((7, 7))
+        );
+        assert_eq!(scanner.first_and_last("pqrstuvwx"), None);
+    }
+
+    #[test]
+    fn suffix_patterns_are_found_too() {
+        let scanner = Scanner::new([("she", 1), ("he", 2), ("hers", 3)]);
+        assert_eq!(values(&scanner, "shers"), [(0, 1), (1, 2), (1, 3)]);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{calibrate_line, NoDigit, Scanner};
+use common::{Error, Result};
+use rayon::prelude::*;
+use std::io::BufRead;
+
+/// Sums the calibration values of every line of `reader` without holding more
+/// than one line in memory.
+///
+/// The sum is a `u64`, so even inputs with billions of lines cannot overflow it.
+pub fn calibrate_reader(reader: impl BufRead, scanner: &Scanner, policy: NoDigit) -> Result<u64> {
+    let mut lines = Lines::new(reader);
+    let mut sum = 0;
+    while let Some((index, line)) = lines.next_line()? {
+        let value = calibrate_line(index, line, scanner, policy)?.value;
+        sum += u64::from(value.unwrap_or(0));
+    }
+    Ok(sum)
+}
+
+/// Like [`calibrate_reader`], but reads `chunk_lines` lines at a time and
+/// calibrates every chunk on the rayon thread pool.
+///
+/// Memory stays bounded by the size of one chunk. Under [`NoDigit::Error`] the
+/// error is the one of the first line without a digit, as in the serial version.
+pub fn calibrate_reader_parallel(
+    reader: impl BufRead,
+    scanner: &Scanner,
+    policy: NoDigit,
+    chunk_lines: usize,
+) -> Result<u64> {
+    let chunk_lines = chunk_lines.max(1);
+    let mut lines = Lines::new(reader);
+    let mut chunk: Vec<String> = Vec::with_capacity(chunk_lines);
+    let mut sum = 0;
+    loop {
+        chunk.clear();
+        let first_index = lines.index;
+        while chunk.len() < chunk_lines {
+            match lines.next_line()? {
+                Some((_, line)) => chunk.push(line.to_string()),
+                None => break,
+            }
+        }
+        if chunk.is_empty() {
+            return Ok(sum);
+        }
+        let values: Vec<Result<Option<u32>>> = chunk
+            .par_iter()
+            .enumerate()
+            .map(|(offset, line)| {
+                calibrate_line(first_index + offset, line, scanner, policy)
+                    .map(|calibration| calibration.value)
+            })
+            .collect();
+        for value in values {
+            sum += u64::from(value?.unwrap_or(0));
+        }
+    }
+}
+
+/// Reads lines into a single reused buffer, like [`str::lines`] does.
+struct Lines<R> {
+    reader: R,
+    buffer: String,
+    /// The 0-based index of the next line.
+    index: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+            index: 0,
+        }
+    }
+
+    /// The index and text of the next line without its line ending.
+    fn next_line(&mut self) -> Result<Option<(usize, &str)>> {
+        self.buffer.clear();
+        match self.reader.read_line(&mut self.buffer) {
+            Ok(0) => return Ok(None),
+            Ok(_) => {}
+            Err(source) => return Err(Error::Io { path: None, source }),
+        }
+        let line = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        self.index += 1;
+        Ok(Some((self.index - 1, line)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vocabulary;
+
+    const EXAMPLE: &str = "two1nine\r\neightwothree\nabcone2threexyz\nxtwone3four\n\
+                           4nineeightseven2\nzoneight234\n7pqrstsixteen";
+
+    #[test]
+    fn streams_the_example() {
+        let scanner = Scanner::with_vocabulary(&Vocabulary::english());
+        let serial = calibrate_reader(EXAMPLE.as_bytes(), &scanner, NoDigit::Error);
+        assert_eq!(serial.unwrap(), 281);
+        for chunk_lines in [1, 2, 3, 100] {
+            let parallel = calibrate_reader_parallel(
+                EXAMPLE.as_bytes(),
+                &scanner,
+                NoDigit::Error,
+                chunk_lines,
+            );
+            assert_eq!(parallel.unwrap(), 281);
+        }
+    }
+
+    #[test]
+    fn reports_the_first_line_without_digit() {
+        let input = "1abc2\nnothing\nnone\n7\n".as_bytes();
+        for chunk_lines in [1, 4] {
+            assert!(matches!(
+                calibrate_reader_parallel(input, &Scanner::numerals(), NoDigit::Error, chunk_lines),
+                Err(Error::Parse { line: 2, .. })
+            ));
+        }
+        assert_eq!(
+            calibrate_reader(input, &Scanner::numerals(), NoDigit::Skip).unwrap(),
+            89
+        );
+    }
+}
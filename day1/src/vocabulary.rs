@@ -0,0 +1,145 @@
+use common::{Error, Result};
+
+pub const ENGLISH: [(&str, u32); 10] = [
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub const GERMAN: [(&str, u32); 10] = [
+    ("null", 0),
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+pub const FRENCH: [(&str, u32); 10] = [
+    ("zéro", 0),
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+
+pub const SPANISH: [(&str, u32); 10] = [
+    ("cero", 0),
+    ("uno", 1),
+    ("dos", 2),
+    ("tres", 3),
+    ("cuatro", 4),
+    ("cinco", 5),
+    ("seis", 6),
+    ("siete", 7),
+    ("ocho", 8),
+    ("nueve", 9),
+];
+
+/// The spelled out digits part 2 looks for besides the ASCII ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// The names [`Vocabulary::builtin`] knows.
+    pub const BUILTIN: [&'static str; 4] = ["english", "german", "french", "spanish"];
+
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Self {
+            words: words
+                .into_iter()
+                .map(|(word, value)| (word.to_string(), value))
+                .collect(),
+        }
+    }
+
+    pub fn english() -> Self {
+        Self::new(ENGLISH)
+    }
+
+    /// One of the [`Vocabulary::BUILTIN`] vocabularies by name.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "english" => Some(Self::new(ENGLISH)),
+            "german" => Some(Self::new(GERMAN)),
+            "french" => Some(Self::new(FRENCH)),
+            "spanish" => Some(Self::new(SPANISH)),
+            _ => None,
+        }
+    }
+
+    /// Reads a TOML table of `word = value` pairs, where every value is a digit.
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|err: toml::de::Error| match err.span() {
+                Some(span) => Error::parse(text, &text[span.start..], "a `word = digit` pair"),
+                None => Error::invalid(err.message()),
+            })?;
+        let words = table
+            .into_iter()
+            .map(|(word, value)| match value.as_integer() {
+                Some(digit @ 0..=9) => Ok((word, digit as u32)),
+                _ => Err(Error::invalid(format!(
+                    "the value of '{word}' is not a digit from 0 to 9"
+                ))),
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { words })
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_toml() {
+        let vocabulary = Vocabulary::from_toml("uno = 1\ndue = 2\n").unwrap();
+        assert_eq!(vocabulary, Vocabulary::new([("due", 2), ("uno", 1)]));
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_digits() {
+        assert_eq!(
+            Vocabulary::from_toml("dieci = 10\n")
+                .unwrap_err()
+                .to_string(),
+            "invalid puzzle: the value of 'dieci' is not a digit from 0 to 9"
+        );
+        assert!(matches!(
+            Vocabulary::from_toml("uno = 1\ndue 2\n"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{parse_game_id, parse_round, Round};
+use common::{Error, Result};
+
+/// One line of the input: the game's ID and the rounds it was played in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game<'a> {
+    pub id: u32,
+    pub rounds: Vec<Round<'a>>,
+}
+
+impl<'a> Game<'a> {
+    /// The most cubes of every color shown in any round, the smallest bag
+    /// the game is possible with.
+    pub fn minimal_bag(&self) -> Round<'a> {
+        max_cubes(std::slice::from_ref(self))
+    }
+}
+
+/// The most cubes of every color shown in any round of any game.
+pub fn max_cubes<'a>(games: &[Game<'a>]) -> Round<'a> {
+    let mut max = Round::new();
+    for round in games.iter().flat_map(|game| &game.rounds) {
+        max.assign_if_larger(round);
+    }
+    max
+}
+
+/// Parses `Game 1: 3 blue, 4 red; 1 red, 2 green`, a whole line of `full`.
+fn parse_game<'a>(full: &'a str, line: &'a str) -> Result<Game<'a>> {
+    let (mut rest, id) =
+        parse_game_id(line).map_err(|err| Error::from_nom(full, err, "`Game <id>: `"))?;
+    let mut rounds = Vec::new();
+    loop {
+        let (after, round) = parse_round(rest)
+            .map_err(|err| Error::from_nom(full, err, "a round like `3 blue, 4 red`"))?;
+        rounds.push(round);
+        match after.strip_prefix("; ") {
+            Some(next) => rest = next,
+            None if after.is_empty() => return Ok(Game { id, rounds }),
+            None => {
+                return Err(Error::parse(
+                    full,
+                    after,
+                    "`, ` and a color, `; ` and a round or the end of the line",
+                ))
+            }
+        }
+    }
+}
+
+/// Parses one game per line.
+pub fn parse_games(full: &str) -> Result<Vec<Game<'_>>> {
+    full.lines().map(|line| parse_game(full, line)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_games() {
+        let games = parse_games("Game 1: 3 blue, 4 red; 1 red\nGame 12: 2 green\n").unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!((games[0].id, games[0].rounds.len()), (1, 2));
+        assert_eq!(
+            max_cubes(&games).cubes().collect::<Vec<_>>(),
+            [("blue", 3), ("red", 4), ("green", 2)]
+        );
+    }
+
+    #[test]
+    fn reports_the_offending_text() {
+        let error = |input| parse_games(input).unwrap_err().to_string();
+        assert_eq!(
+            error("Game 1: 3 blue\nGame two: 4 red\n"),
+            "line 2, column 6: found 'two:', expected `Game <id>: `"
+        );
+        assert_eq!(
+            error("Game 1: 3 blue, red\n"),
+            "line 1, column 17: found 'red', expected a round like `3 blue, 4 red`"
+        );
+        assert_eq!(
+            error("Game 1: 3 blue 4 red\n"),
+            "line 1, column 16: found '4', expected `, ` and a color, `; ` and a round or the end of the line"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Day 2: Cube Conundrum. Games of cubes drawn from a bag.
+
+use common::{Error, Result, Solution};
+
+mod game;
+mod limits;
+mod stats;
+
+pub use game::{max_cubes, parse_games, Game};
+pub use limits::{check_games, Failure, GameCheck, Limits};
+use nom::bytes::complete::*;
+use nom::character::complete::*;
+use nom::combinator::cut;
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom::sequence::tuple;
+use nom::IResult;
+pub use stats::{ColorStats, MinimalBag, Stats};
+
+/// The colors of the puzzle's bag.
+pub const PUZZLE_COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// The cubes of every color shown in one round, in the order they were named.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Round<'a> {
+    cubes: Vec<(&'a str, u32)>,
+}
+
+impl<'a> Round<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// A round that shows none of `colors`, which still count towards the [`Round::power`].
+    pub fn with_colors(colors: impl IntoIterator<Item = &'a str>) -> Self {
+        Self {
+            cubes: colors.into_iter().map(|color| (color, 0)).collect(),
+        }
+    }
+    pub fn add(&mut self, amount: u32, color: &'a str) {
+        match self.cubes.iter_mut().find(|(known, _)| *known == color) {
+            Some((_, count)) => *count += amount,
+            None => self.cubes.push((color, amount)),
+        }
+    }
+    pub fn count(&self, color: &str) -> u32 {
+        self.cubes
+            .iter()
+            .find(|(known, _)| *known == color)
+            .map_or(0, |(_, count)| *count)
+    }
+    pub fn cubes(&self) -> impl Iterator<Item = (&'a str, u32)> + '_ {
+        self.cubes.iter().copied()
+    }
+    /// The first color of which the round shows more cubes than the bag holds.
+    pub fn exceeded(&self, limits: &Limits) -> Option<&'a str> {
+        self.cubes()
+            .find(|&(color, count)| count > limits.count(color))
+            .map(|(color, _)| color)
+    }
+    /// Whether a bag with `limits` cubes allows the round.
+    pub fn is_valid(&self, limits: &Limits) -> bool {
+        self.exceeded(limits).is_none()
+    }
+    pub fn assign_if_larger(&mut self, round: &Round<'a>) {
+        for (color, count) in round.cubes() {
+            match self.cubes.iter_mut().find(|(known, _)| *known == color) {
+                Some((_, known)) => *known = (*known).max(count),
+                None => self.cubes.push((color, count)),
+            }
+        }
+    }
+    /// The product of the counts of all colors, `None` if it does not fit
+    /// into 64 bits.
+    pub fn power(&self) -> Option<u64> {
+        self.cubes.iter().try_fold(1u64, |power, (_, count)| {
+            power.checked_mul(u64::from(*count))
+        })
+    }
+}
+
+/// Parses a color name, any word of ASCII letters.
+pub fn parse_color(input: &str) -> IResult<&str, &str> {
+    alpha1(input)
+}
+
+/// Parses `3 blue, 4 red`; after a `, ` there must be another color.
+pub fn parse_round(input: &str) -> IResult<&str, Round<'_>> {
+    let mut round = Round::new();
+    let cubes = || separated_pair(u32, tag(" "), parse_color);
+    let (input, pairs) = separated_list1(tag(", "), cut(cubes()))(input)?;
+    for pair in pairs {
+        round.add(pair.0, pair.1);
+    }
+    Ok((input, round))
+}
+
+/// Parses `Game 1: `.
+pub fn parse_game_id(input: &str) -> IResult<&str, u32> {
+    let (input, (_, game_id, _)) = tuple((tag("Game "), u32, tag(": ")))(input)?;
+    Ok((input, game_id))
+}
+
+/// Sums the IDs of the games possible with the puzzle's [`Limits`].
+pub fn part1(games: &[Game]) -> u64 {
+    check_games(games, &Limits::default())
+        .iter()
+        .filter(|game| game.failure.is_none())
+        .map(|game| u64::from(game.id))
+        .sum()
+}
+
+/// Sums the power of the smallest bag that allows every game. Colors of the
+/// puzzle a game never shows make its power 0.
+pub fn part2(games: &[Game]) -> Result<u64> {
+    let mut sum = 0u64;
+    for game in games {
+        let mut bag = Round::with_colors(PUZZLE_COLORS);
+        bag.assign_if_larger(&game.minimal_bag());
+        sum = bag
+            .power()
+            .and_then(|power| sum.checked_add(power))
+            .ok_or_else(|| {
+                Error::invalid(format!(
+                    "the power of game {} does not fit into 64 bits",
+                    game.id
+                ))
+            })?;
+    }
+    Ok(sum)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game<'a>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_games(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<u64> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<u64> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn parses_rounds() {
+        let (rest, game_id) = parse_game_id(EXAMPLE).unwrap();
+        assert_eq!(game_id, 1);
+        let (_, round) = parse_round(rest).unwrap();
+        assert_eq!(round.cubes().collect::<Vec<_>>(), [("blue", 3), ("red", 4)]);
+    }
+
+    #[test]
+    fn round_validity() {
+        let limits = Limits::default();
+        let (_, round) = parse_round("8 green, 6 blue, 20 red; ").unwrap();
+        assert_eq!(round.exceeded(&limits), Some("red"));
+        let (_, round) = parse_round("12 red, 13 green, 14 blue\n").unwrap();
+        assert!(round.is_valid(&limits));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input).unwrap(), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input).unwrap(), 2286);
+    }
+
+    #[test]
+    fn malformed_round_is_an_error() {
+        assert_eq!(
+            Day2::parse("Game 1: 3 blue\nGame 2: four red\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 9: found 'four', expected a round like `3 blue, 4 red`"
+        );
+    }
+
+    #[test]
+    fn extra_colors() {
+        let games = parse_games(
+            "Game 1: 3 blue, 2 yellow; 1 red, 2 green\nGame 2: 1 red, 1 green, 1 blue\n",
+        )
+        .unwrap();
+        // Game 1 needs a yellow cube the puzzle's bag does not have.
+        assert_eq!(part1(&games), 2);
+        // 1 * 2 * 3 * 2 + 1
+        assert_eq!(part2(&games).unwrap(), 13);
+        let mut limits = Limits::default();
+        limits.set("yellow", 2);
+        assert_eq!(check_games(&games, &limits)[0].failure, None);
+    }
+
+    #[test]
+    fn power_that_overflows_is_an_error() {
+        let games = parse_games(
+            "Game 1: 100 red, 100 green, 100 blue, 100 yellow, 100 pink\n\
+             Game 2: 4000000000 red, 4000000000 green, 4000000000 blue\n",
+        )
+        .unwrap();
+        assert_eq!(games[0].minimal_bag().power(), Some(10_000_000_000));
+        assert_eq!(
+            part2(&games).unwrap_err().to_string(),
+            "invalid puzzle: the power of game 2 does not fit into 64 bits"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{Game, PUZZLE_COLORS};
+use common::{Error, Result};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+/// How many cubes of every color the bag holds; it holds none of the colors
+/// it does not know.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Limits {
+    cubes: BTreeMap<String, u32>,
+}
+
+/// The bag of the puzzle: 12 red, 13 green and 14 blue cubes.
+impl Default for Limits {
+    fn default() -> Self {
+        Self::new(PUZZLE_COLORS.into_iter().zip([12, 13, 14]))
+    }
+}
+
+impl Limits {
+    pub fn new<'a>(cubes: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Self {
+            cubes: cubes
+                .into_iter()
+                .map(|(color, count)| (color.to_string(), count))
+                .collect(),
+        }
+    }
+
+    pub fn count(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn set(&mut self, color: &str, count: u32) {
+        self.cubes.insert(color.to_string(), count);
+    }
+
+    /// Reads `color = count` pairs from TOML; the puzzle's colors that are
+    /// missing keep their limit.
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|err: toml::de::Error| match err.span() {
+                Some(span) => Error::parse(text, &text[span.start..], "a `color = count` pair"),
+                None => Error::invalid(err.message()),
+            })?;
+        let mut limits = Self::default();
+        for (color, value) in table {
+            let count = value
+                .as_integer()
+                .and_then(|count| u32::try_from(count).ok())
+                .ok_or_else(|| Error::invalid(format!("the limit of {color} is not a count")))?;
+            limits.set(&color, count);
+        }
+        Ok(limits)
+    }
+}
+
+/// Why a game is impossible: the first round that shows more cubes of a color
+/// than the bag holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// The 1-based round within the game.
+    pub round: usize,
+    pub color: &'a str,
+    pub shown: u32,
+    pub limit: u32,
+}
+
+/// Whether a game is possible with some [`Limits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameCheck<'a> {
+    pub id: u32,
+    /// `None` if the game is possible.
+    pub failure: Option<Failure<'a>>,
+}
+
+impl Display for GameCheck<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.failure {
+            None => write!(f, "Game {}: possible", self.id),
+            Some(Failure {
+                round,
+                color,
+                shown,
+                limit,
+            }) => write!(
+                f,
+                "Game {}: impossible, round {round} shows {shown} {color} but the bag holds {limit}",
+                self.id
+            ),
+        }
+    }
+}
+
+impl<'a> Game<'a> {
+    /// The first round and color that make the game impossible with `limits`.
+    pub fn check(&self, limits: &Limits) -> GameCheck<'a> {
+        let failure = (1..).zip(&self.rounds).find_map(|(index, round)| {
+            round.exceeded(limits).map(|color| Failure {
+                round: index,
+                color,
+                shown: round.count(color),
+                limit: limits.count(color),
+            })
+        });
+        GameCheck {
+            id: self.id,
+            failure,
+        }
+    }
+}
+
+/// Checks every game against `limits`.
+pub fn check_games<'a>(games: &[Game<'a>], limits: &Limits) -> Vec<GameCheck<'a>> {
+    games.iter().map(|game| game.check(limits)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_games;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+";
+
+    #[test]
+    fn reports_the_failing_round_and_color() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let report: Vec<_> = check_games(&games, &Limits::default())
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            report,
+            [
+                "Game 1: possible",
+                "Game 3: impossible, round 1 shows 20 red but the bag holds 12",
+                "Game 4: impossible, round 3 shows 15 blue but the bag holds 14",
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_limits() {
+        let limits = Limits::from_toml("red = 20\nblue = 15\n").unwrap();
+        assert_eq!(
+            limits,
+            Limits::new([("red", 20), ("green", 13), ("blue", 15)])
+        );
+        let games = parse_games(EXAMPLE).unwrap();
+        let possible: Vec<_> = check_games(&games, &limits)
+            .into_iter()
+            .filter(|game| game.failure.is_none())
+            .map(|game| game.id)
+            .collect();
+        assert_eq!(possible, [1, 3, 4]);
+        assert!(Limits::from_toml("purple = -3\n").is_err());
+    }
+}
@@ -1,144 +1,106 @@
-use anyhow::Result;
-use nom::branch::alt;
-use nom::bytes::complete::*;
-use nom::character::complete::*;
-use nom::multi::separated_list1;
-use nom::sequence::tuple;
-use nom::sequence::{separated_pair, terminated};
-use nom::IResult;
-use std::fs::read_to_string;
+use anyhow::{Context, Result};
+use clap::Parser;
+use common::{Format, InputSource, Inputs, Part, PartAnswer, Reporter, Solution};
+use day2::{Day2, Limits, Stats};
+use std::{fs, path::PathBuf};
 
-const NUM_RED: u32 = 12;
-const NUM_GREEN: u32 = 13;
-const NUM_BLUE: u32 = 14;
-
-#[derive(Debug)]
-enum Color {
-    Red,
-    Green,
-    Blue,
+#[derive(Parser)]
+#[command(about = "Day 2: Cube Conundrum")]
+struct Args {
+    /// Read the input from this file, `-` for stdin [default: day2/input.txt in the inputs directory]
+    input: Option<String>,
+    /// TOML file with the `red`, `green` and `blue` cubes in the bag [default: 12, 13 and 14]
+    #[arg(long)]
+    limits: Option<PathBuf>,
+    /// Red cubes in the bag, overriding --limits
+    #[arg(long)]
+    red: Option<u32>,
+    /// Green cubes in the bag, overriding --limits
+    #[arg(long)]
+    green: Option<u32>,
+    /// Blue cubes in the bag, overriding --limits
+    #[arg(long)]
+    blue: Option<u32>,
+    /// Cubes of any other color in the bag, like `yellow=3`
+    #[arg(long, value_name = "COLOR=COUNT", value_parser = parse_cubes)]
+    cubes: Vec<(String, u32)>,
+    /// List which games are possible with the bag and why the others are not
+    #[arg(long)]
+    query: bool,
+    /// Print statistics over all rounds as a `table` or as `json`
+    #[arg(long, value_name = "FORMAT", value_parser = ["table", "json"])]
+    stats: Option<String>,
 }
-impl TryFrom<&str> for Color {
-    type Error = &'static str;
 
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        match s {
-            "red" => Ok(Self::Red),
-            "green" => Ok(Self::Green),
-            "blue" => Ok(Self::Blue),
-            _ => Err("Could not convert &str to Color"),
-        }
-    }
-}
-#[derive(Debug)]
-struct Round {
-    red: u32,
-    green: u32,
-    blue: u32,
-}
-
-impl Round {
-    fn new() -> Self {
-        Self {
-            red: 0,
-            green: 0,
-            blue: 0,
-        }
-    }
-    fn add(&mut self, amount: u32, color: Color) {
-        match color {
-            Color::Red => self.red += amount,
-            Color::Green => self.green += amount,
-            Color::Blue => self.blue += amount,
-        }
-    }
-    fn is_valid(&self) -> bool {
-        if self.red <= NUM_RED && self.green <= NUM_GREEN && self.blue <= NUM_BLUE {
-            true
-        } else {
-            false
-        }
-    }
-    fn assign_if_larger(&mut self, round: &Round) {
-        if self.red < round.red {
-            self.red = round.red
-        }
-        if self.green < round.green {
-            self.green = round.green
-        }
-        if self.blue < round.blue {
-            self.blue = round.blue
-        }
-    }
-    fn cube(&self) -> u32 {
-       self.red * self.green * self.blue
-    }
+fn parse_cubes(s: &str) -> Result<(String, u32), String> {
+    let (color, count) = s
+        .split_once('=')
+        .ok_or_else(|| format!("'{s}' is not like `yellow=3`"))?;
+    let count = count
+        .parse()
+        .map_err(|_| format!("'{count}' is not a number of cubes"))?;
+    Ok((color.to_string(), count))
 }
 
-fn parse_color(input: &str) -> IResult<&str, Color> {
-    let (input, color) = alt((tag("red"), tag("green"), tag("blue")))(input)?;
-    Ok((input, Color::try_from(color).unwrap()))
-}
-
-fn parse_round(input: &str) -> IResult<&str, Round> {
-    let mut round = Round::new();
-    let (input, pairs) = terminated(
-        separated_list1(tag(", "), separated_pair(u32, tag(" "), parse_color)),
-        alt((tag("; "), tag("\n"))),
-    )(input)?;
-    for pair in pairs {
-        round.add(pair.0, pair.1);
-    }
-    Ok((input, round))
-}
-
-fn parse_game_id(input: &str) -> IResult<&str, u32> {
-    let (input, (_, game_id, _)) = tuple((tag("Game "), u32, tag(": ")))(input)?;
-    Ok((input, game_id))
-}
-
-fn part1(mut input: &str) {
-    let mut sum = 0;
-    loop {
-        let Ok((input1, game_id)) = parse_game_id(&input) else {
-            break;
+impl Args {
+    fn limits(&self) -> Result<Limits> {
+        let mut limits = match &self.limits {
+            Some(path) => {
+                let text = fs::read_to_string(path)
+                    .with_context(|| format!("could not read '{}'", path.display()))?;
+                Limits::from_toml(&text).with_context(|| format!("'{}'", path.display()))?
+            }
+            None => Limits::default(),
         };
-        input = input1;
-        let mut game_valid = true;
-        while let Ok((input1, round)) = parse_round(input) {
-            if !round.is_valid() {
-                game_valid = false;
+        let flags = [
+            ("red", self.red),
+            ("green", self.green),
+            ("blue", self.blue),
+        ];
+        for (color, count) in flags {
+            if let Some(count) = count {
+                limits.set(color, count);
             }
-            input = input1
         }
-        if game_valid {
-            sum += game_id;
+        for (color, count) in &self.cubes {
+            limits.set(color, *count);
         }
+        Ok(limits)
     }
-    println!("Day 2 Part 1: {sum}");
 }
 
-fn part2(mut input: &str) {
-    let mut sum = 0;
-    loop {
-        let mut min_cubes = Round::new();
-        let Ok((input1, _)) = parse_game_id(&input) else {
-            break;
-        };
-        input = input1;
-        while let Ok((input1, round)) = parse_round(input) {
-            min_cubes.assign_if_larger(&round);
-            input = input1
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let limits = args.limits()?;
+    let source = args
+        .input
+        .as_deref()
+        .map_or(InputSource::Default, InputSource::from);
+    let input = Inputs::from_env()
+        .read(Day2::DAY, &source)
+        .context("Day 2")?;
+    let games = Day2::parse(&input).context("Day 2")?;
+    match args.stats.as_deref() {
+        Some("json") => println!("{}", Stats::of(&games).to_json()),
+        Some(_) => print!("{}", Stats::of(&games).to_table()),
+        None => {}
+    }
+    if args.query {
+        for check in day2::check_games(&games, &limits) {
+            println!("{check}");
         }
-        sum += min_cubes.cube();
     }
-    println!("Day 2 Part 2: {sum}");
-}
-
-fn main() -> Result<()> {
-    let input = read_to_string("input.txt")?;
-    part1(&input);
-    part2(&input);
-
+    let part1 = PartAnswer::timed(Part::One, || {
+        Ok(day2::check_games(&games, &limits)
+            .iter()
+            .filter(|game| game.failure.is_none())
+            .map(|game| game.id)
+            .sum::<u32>())
+    });
+    let part2 = PartAnswer::timed(Part::Two, || day2::part2(&games));
+    let mut reporter = Reporter::stdout(Format::Text);
+    for answer in [part1, part2] {
+        reporter.report(Day2::DAY, &answer.context("Day 2")?)?;
+    }
     Ok(())
 }
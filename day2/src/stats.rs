@@ -0,0 +1,146 @@
+use crate::{max_cubes, Game};
+use serde::Serialize;
+use std::{collections::BTreeMap, fmt::Write};
+
+/// How many cubes of one color the rounds showed. A round that does not name
+/// the color showed 0 of it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColorStats {
+    /// The rounds that named the color.
+    pub shown_in: usize,
+    pub total: u64,
+    pub min: u32,
+    pub max: u32,
+    pub mean: f64,
+    /// How many rounds showed how many cubes.
+    pub histogram: BTreeMap<u32, usize>,
+    /// Maximum-likelihood estimate of the cubes of this color in the bag.
+    ///
+    /// If every round shows a uniformly random number of cubes between 0 and
+    /// the number `n` in the bag, `k` rounds are observed with probability
+    /// `(n + 1)^-k` as long as `n` is at least the largest count shown, and 0
+    /// otherwise. The likelihood is largest for the smallest possible bag,
+    /// which is the most cubes ever shown.
+    pub estimate: u32,
+}
+
+/// The smallest bag a single game is possible with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MinimalBag<'a> {
+    pub id: u32,
+    pub cubes: BTreeMap<&'a str, u32>,
+}
+
+/// Statistics over all rounds of all games.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats<'a> {
+    pub games: usize,
+    pub rounds: usize,
+    pub colors: BTreeMap<&'a str, ColorStats>,
+    pub minimal_bags: Vec<MinimalBag<'a>>,
+}
+
+impl<'a> Stats<'a> {
+    pub fn of(games: &[Game<'a>]) -> Self {
+        let rounds: Vec<_> = games.iter().flat_map(|game| &game.rounds).collect();
+        let colors = max_cubes(games)
+            .cubes()
+            .map(|(color, max)| {
+                let mut histogram = BTreeMap::new();
+                for round in &rounds {
+                    *histogram.entry(round.count(color)).or_insert(0) += 1;
+                }
+                let total = rounds
+                    .iter()
+                    .map(|round| u64::from(round.count(color)))
+                    .sum();
+                let stats = ColorStats {
+                    shown_in: rounds
+                        .iter()
+                        .filter(|round| round.cubes().any(|(shown, _)| shown == color))
+                        .count(),
+                    total,
+                    min: histogram.keys().next().copied().unwrap_or(0),
+                    max,
+                    mean: total as f64 / rounds.len() as f64,
+                    histogram,
+                    estimate: max,
+                };
+                (color, stats)
+            })
+            .collect();
+        Self {
+            games: games.len(),
+            rounds: rounds.len(),
+            colors,
+            minimal_bags: games
+                .iter()
+                .map(|game| MinimalBag {
+                    id: game.id,
+                    cubes: game.minimal_bag().cubes().collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// A table of the colors followed by the smallest bag of every game.
+    pub fn to_table(&self) -> String {
+        let mut table = format!("{} games, {} rounds\n\n", self.games, self.rounds);
+        let _ = writeln!(
+            table,
+            "{:<10} {:>8} {:>8} {:>5} {:>5} {:>7} {:>8}",
+            "color", "shown in", "total", "min", "max", "mean", "estimate"
+        );
+        for (color, stats) in &self.colors {
+            let _ = writeln!(
+                table,
+                "{color:<10} {:>8} {:>8} {:>5} {:>5} {:>7.2} {:>8}",
+                stats.shown_in, stats.total, stats.min, stats.max, stats.mean, stats.estimate
+            );
+        }
+        table.push('\n');
+        for bag in &self.minimal_bags {
+            let cubes: Vec<_> = bag
+                .cubes
+                .iter()
+                .map(|(color, count)| format!("{count} {color}"))
+                .collect();
+            let _ = writeln!(table, "Game {}: {}", bag.id, cubes.join(", "));
+        }
+        table
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("statistics are always valid JSON")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_games;
+
+    #[test]
+    fn color_statistics() {
+        let games =
+            parse_games("Game 1: 3 blue, 4 red; 1 red\nGame 2: 2 blue; 6 blue, 1 green\n").unwrap();
+        let stats = Stats::of(&games);
+        assert_eq!((stats.games, stats.rounds), (2, 4));
+        let blue = &stats.colors["blue"];
+        assert_eq!(
+            (blue.shown_in, blue.total, blue.min, blue.max),
+            (3, 11, 0, 6)
+        );
+        assert_eq!(blue.mean, 2.75);
+        assert_eq!(
+            blue.histogram,
+            BTreeMap::from([(0, 1), (2, 1), (3, 1), (6, 1)])
+        );
+        assert_eq!(blue.estimate, 6);
+        assert_eq!(
+            stats.minimal_bags[1].cubes,
+            BTreeMap::from([("blue", 6), ("green", 1)])
+        );
+        assert!(stats.to_table().contains("Game 1: 3 blue, 4 red\n"));
+    }
+}
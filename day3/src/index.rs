@@ -0,0 +1,253 @@
+use crate::{Neighborhood, Rules, Schematic, SymbolQuery};
+use common::{Error, Result, Run};
+use std::{collections::HashMap, ops::Range};
+
+/// A number of the schematic. Rows and columns are 0-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub columns: Range<usize>,
+    /// Indices into [`PartIndex::symbols`] of the symbols next to the number.
+    pub symbols: Vec<usize>,
+}
+
+impl Number {
+    /// Whether a symbol is next to the number.
+    pub fn is_part(&self) -> bool {
+        !self.symbols.is_empty()
+    }
+}
+
+/// A symbol of the schematic. Rows and columns are 0-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub row: usize,
+    pub column: usize,
+    /// Indices into [`PartIndex::numbers`] of the numbers next to the symbol.
+    pub numbers: Vec<usize>,
+}
+
+/// A `*` next to exactly two numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gear<'a> {
+    pub symbol: &'a Symbol,
+    pub numbers: [&'a Number; 2],
+}
+
+impl Gear<'_> {
+    pub fn ratio(&self) -> u64 {
+        u64::from(self.numbers[0].value) * u64::from(self.numbers[1].value)
+    }
+}
+
+/// Every number and symbol of a schematic and which of them are neighbors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartIndex {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    by_position: HashMap<(usize, usize), usize>,
+}
+
+impl PartIndex {
+    /// Indexes `schematic` under the puzzle's rules.
+    pub fn new(schematic: &Schematic) -> Result<Self> {
+        Self::with_rules(schematic, &Rules::default())
+    }
+
+    pub fn with_rules(schematic: &Schematic, rules: &Rules) -> Result<Self> {
+        let cells = schematic.cells();
+        let mut symbols = Vec::new();
+        let mut by_position = HashMap::new();
+        for ((row, column), cell) in cells.iter() {
+            if rules.is_symbol(*cell) {
+                by_position.insert((row, column), symbols.len());
+                symbols.push(Symbol {
+                    symbol: char::from(*cell),
+                    row,
+                    column,
+                    numbers: Vec::new(),
+                });
+            }
+        }
+
+        let mut numbers = Vec::new();
+        for Run { row, columns } in cells.runs(u8::is_ascii_digit) {
+            let digits = &cells.row(row)[columns.clone()];
+            let value = digits
+                .iter()
+                .try_fold(0u32, |num, digit| {
+                    num.checked_mul(10)?.checked_add(u32::from(digit - b'0'))
+                })
+                .ok_or_else(|| Error::Parse {
+                    line: row + 1,
+                    column: columns.start + 1,
+                    token: String::from_utf8_lossy(digits).into_owned(),
+                    expected: "a number that fits into 32 bits".to_string(),
+                })?;
+
+            // a symbol next to several digits turns up once for each of them
+            let mut neighbors: Vec<_> = match rules.neighborhood {
+                Neighborhood::Adjacent(connectivity) => columns
+                    .clone()
+                    .flat_map(|column| cells.neighbors((row, column), connectivity))
+                    .filter_map(|pos| by_position.get(&pos).copied())
+                    .collect(),
+                Neighborhood::Radius(radius) => cells
+                    .region(
+                        row.saturating_sub(radius)..row.saturating_add(radius).saturating_add(1),
+                        columns.start.saturating_sub(radius)..columns.end.saturating_add(radius),
+                    )
+                    .filter_map(|(pos, _)| by_position.get(&pos).copied())
+                    .collect(),
+            };
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            for &symbol in &neighbors {
+                symbols[symbol].numbers.push(numbers.len());
+            }
+            numbers.push(Number {
+                value,
+                row,
+                columns,
+                symbols: neighbors,
+            });
+        }
+        Ok(Self {
+            numbers,
+            symbols,
+            by_position,
+        })
+    }
+
+    /// All numbers, row by row from left to right.
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    /// All symbols, row by row from left to right.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The symbol at a 0-based position, if there is one.
+    pub fn symbol_at(&self, row: usize, column: usize) -> Option<&Symbol> {
+        self.by_position
+            .get(&(row, column))
+            .map(|&index| &self.symbols[index])
+    }
+
+    /// The numbers next to `symbol`.
+    pub fn touching<'a>(&'a self, symbol: &'a Symbol) -> impl Iterator<Item = &'a Number> + 'a {
+        symbol.numbers.iter().map(|&index| &self.numbers[index])
+    }
+
+    /// The numbers next to at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(|number| number.is_part())
+    }
+
+    /// Combines the numbers next to every symbol that matches `query`.
+    pub fn aggregate(&self, query: &SymbolQuery) -> Result<Vec<(&Symbol, u64)>> {
+        self.symbols
+            .iter()
+            .filter(|symbol| query.symbol.is_none_or(|wanted| symbol.symbol == wanted))
+            .filter(|symbol| query.numbers.is_none_or(|count| symbol.numbers.len() == count))
+            .map(|symbol| {
+                let value = query
+                    .aggregate
+                    .apply(self.touching(symbol).map(|number| number.value))
+                    .ok_or_else(|| {
+                        Error::invalid(format!(
+                            "the {} of the numbers next to '{}' at line {}, column {} does not fit into 64 bits",
+                            query.aggregate,
+                            symbol.symbol,
+                            symbol.row + 1,
+                            symbol.column + 1
+                        ))
+                    })?;
+                Ok((symbol, value))
+            })
+            .collect()
+    }
+
+    /// Every `*` next to exactly two numbers.
+    pub fn gears(&self) -> impl Iterator<Item = Gear<'_>> {
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.symbol == '*')
+            .filter_map(|symbol| match symbol.numbers[..] {
+                [first, second] => Some(Gear {
+                    symbol,
+                    numbers: [&self.numbers[first], &self.numbers[second]],
+                }),
+                _ => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexes_numbers_and_symbols() {
+        let schematic = Schematic::parse("467..114..\n...*......\n..35..633.\n").unwrap();
+        let index = PartIndex::new(&schematic).unwrap();
+        let values: Vec<_> = index.numbers().iter().map(|number| number.value).collect();
+        assert_eq!(values, [467, 114, 35, 633]);
+        assert_eq!(index.numbers()[2].columns, 2..4);
+
+        let star = index.symbol_at(1, 3).unwrap();
+        let touching: Vec<_> = index.touching(star).map(|number| number.value).collect();
+        assert_eq!(touching, [467, 35]);
+        assert_eq!(index.symbol_at(0, 0), None);
+        assert_eq!(
+            index.gears().map(|gear| gear.ratio()).collect::<Vec<_>>(),
+            [16345]
+        );
+        assert!(!index.numbers()[1].is_part());
+    }
+
+    #[test]
+    fn follows_the_rules() {
+        use crate::{Aggregate, Symbols};
+        use common::Connectivity;
+
+        let schematic = Schematic::parse("2..3.\n.#*..\n4..#5\n").unwrap();
+        let four = Rules {
+            neighborhood: Neighborhood::Adjacent(Connectivity::Four),
+            ..Rules::default()
+        };
+        let index = PartIndex::with_rules(&schematic, &four).unwrap();
+        let parts: Vec<_> = index.part_numbers().map(|number| number.value).collect();
+        assert_eq!(parts, [5]);
+
+        let radius = Rules {
+            symbols: Symbols::Only(b"#".to_vec()),
+            neighborhood: Neighborhood::Radius(2),
+            ..Rules::default()
+        };
+        let index = PartIndex::with_rules(&schematic, &radius).unwrap();
+        let query = SymbolQuery {
+            symbol: None,
+            numbers: None,
+            aggregate: Aggregate::Sum,
+        };
+        let sums: Vec<_> = index
+            .aggregate(&query)
+            .unwrap()
+            .into_iter()
+            .map(|(symbol, sum)| (symbol.column, sum))
+            .collect();
+        assert_eq!(sums, [(1, 9), (3, 8)]);
+
+        let everywhere = Rules {
+            neighborhood: Neighborhood::Radius(usize::MAX),
+            ..Rules::default()
+        };
+        let index = PartIndex::with_rules(&schematic, &everywhere).unwrap();
+        assert_eq!(index.part_numbers().count(), 4);
+    }
+}
@@ -0,0 +1,81 @@
+//! Day 3: Gear Ratios. Part numbers in an engine schematic.
+
+mod index;
+mod render;
+mod rules;
+mod schematic;
+
+use common::{Error, Result, Solution};
+
+pub use index::{Gear, Number, PartIndex, Symbol};
+pub use render::{classify, render, Cell, Render};
+pub use rules::{Aggregate, Neighborhood, Rules, SymbolQuery, Symbols};
+pub use schematic::Schematic;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = PartIndex;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        PartIndex::new(&Schematic::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<u64> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<u64> {
+        part2(input)
+    }
+}
+
+/// Sums the numbers next to a symbol.
+pub fn part1(index: &PartIndex) -> u64 {
+    index
+        .part_numbers()
+        .map(|number| u64::from(number.value))
+        .sum()
+}
+
+/// Sums the gear ratios.
+pub fn part2(index: &PartIndex) -> Result<u64> {
+    index
+        .aggregate(&SymbolQuery::GEAR_RATIOS)?
+        .into_iter()
+        .try_fold(0u64, |sum, (_, ratio)| sum.checked_add(ratio))
+        .ok_or_else(|| Error::invalid("the sum of the gear ratios does not fit into 64 bits"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&input).unwrap(), 4361);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part2(&input).unwrap(), 467835);
+    }
+}
@@ -1,46 +1,80 @@
-use anyhow::Result;
-use ndarray::{s, Array};
-use ndarray_ndimage::{pad, PadMode};
-use std::fs::read_to_string;
+use anyhow::{Context, Result};
+use clap::Parser;
+use common::{Format, InputSource, Inputs, Part, PartAnswer, Reporter, Solution};
+use day3::{
+    Aggregate, Day3, Neighborhood, PartIndex, Render, Rules, Schematic, SymbolQuery, Symbols,
+};
 
-const DIM: usize = 140;
+#[derive(Parser)]
+#[command(about = "Day 3: Gear Ratios")]
+struct Args {
+    /// Read the input from this file, `-` for stdin [default: day3/input.txt in the inputs directory]
+    input: Option<String>,
+    /// Only these characters are symbols [default: any ASCII punctuation]
+    #[arg(long)]
+    symbols: Option<String>,
+    /// Characters that are neither digits nor symbols
+    #[arg(long, default_value = ".")]
+    blanks: String,
+    /// Which cells are next to a number: 4, 8 or radius:<N> for any cell at most N rows and columns away
+    #[arg(long, default_value_t = Neighborhood::default())]
+    neighborhood: Neighborhood,
+    /// Print the sum, product or count of the numbers next to every symbol
+    #[arg(long)]
+    aggregate: Option<Aggregate>,
+    /// With --aggregate, only this symbol
+    #[arg(long, requires = "aggregate")]
+    symbol: Option<char>,
+    /// With --aggregate, only symbols next to exactly this many numbers
+    #[arg(long, requires = "aggregate")]
+    numbers: Option<usize>,
+    /// Instead of the answers, print the schematic with the part numbers, other numbers, symbols
+    /// and gears highlighted: ansi for a terminal, svg or html
+    #[arg(long, value_name = "FORMAT")]
+    render: Option<Render>,
+}
 
 fn main() -> Result<()> {
-    let mut sum = 0;
-    let input = read_to_string("input.txt")?;
-    let input = input.replace("\n", "");
-    let mat = Array::from_shape_vec((DIM, DIM), input.into_bytes())?;
-    let mat = pad(&mat, &[[1usize, 1usize]], PadMode::Constant(b'.'));
-    let mut skip = 0;
-    for (index, elem) in mat.indexed_iter() {
-        if skip > 0 {
-            skip -= 1;
-            continue;
-        }
-        if elem.is_ascii_digit() {
-            let start = index.1;
-            let mut offset = 0;
-            while mat[[index.0, start + offset + 1]].is_ascii_digit() {
-                offset += 1;
-            }
-            let num = mat.slice(s!(index.0, start..=start + offset));
-            let num = num.as_slice().unwrap();
-            let num = u32::from_str_radix(std::str::from_utf8(num).unwrap(), 10).unwrap();
-
-            // look for symbols
-            let frame = mat.slice(s!(
-                index.0 - 1..=index.0 + 1,
-                start - 1..=start + offset + 1
-            ));
-            let count = frame
-                .iter()
-                .any(|b| !b.is_ascii_digit() && *b != b'.' && b.is_ascii_punctuation());
-            if count == true {
-                sum += num;
-                skip = offset
-            }
+    let args = Args::parse();
+    let rules = Rules {
+        symbols: args.symbols.map_or(Symbols::Punctuation, |symbols| {
+            Symbols::Only(symbols.into_bytes())
+        }),
+        blanks: args.blanks.into_bytes(),
+        neighborhood: args.neighborhood,
+    };
+    let source = args
+        .input
+        .as_deref()
+        .map_or(InputSource::Default, InputSource::from);
+    let input = Inputs::from_env()
+        .read(Day3::DAY, &source)
+        .context("Day 3")?;
+    let schematic = Schematic::parse(&input).context("Day 3")?;
+    let index = PartIndex::with_rules(&schematic, &rules).context("Day 3")?;
+    if let Some(render) = args.render {
+        print!("{}", day3::render(&schematic, &index, render));
+        return Ok(());
+    }
+    if let Some(aggregate) = args.aggregate {
+        let query = SymbolQuery {
+            symbol: args.symbol,
+            numbers: args.numbers,
+            aggregate,
+        };
+        for (symbol, value) in index.aggregate(&query).context("Day 3")? {
+            println!(
+                "'{}' at line {}, column {}: {value}",
+                symbol.symbol,
+                symbol.row + 1,
+                symbol.column + 1
+            );
         }
     }
-    println!("Day 3 Part 1: {sum}");
+    let mut reporter = Reporter::stdout(Format::Text);
+    let part1 = PartAnswer::timed(Part::One, || Ok(day3::part1(&index)))?;
+    reporter.report(Day3::DAY, &part1)?;
+    let part2 = PartAnswer::timed(Part::Two, || day3::part2(&index))?;
+    reporter.report(Day3::DAY, &part2)?;
     Ok(())
 }
@@ -0,0 +1,228 @@
+use crate::{PartIndex, Schematic};
+use common::Grid;
+use std::{
+    fmt::{self, Display, Write},
+    str::FromStr,
+};
+
+/// What a cell of the schematic turned out to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Cell {
+    /// A blank or any other byte that is neither a digit nor a symbol.
+    #[default]
+    Blank,
+    /// A digit of a number that is not next to a symbol.
+    Number,
+    /// A digit of a part number.
+    Part,
+    Symbol,
+    /// A `*` next to exactly two numbers.
+    Gear,
+}
+
+impl Cell {
+    pub const ALL: [Cell; 5] = [
+        Cell::Blank,
+        Cell::Number,
+        Cell::Part,
+        Cell::Symbol,
+        Cell::Gear,
+    ];
+
+    /// The name of the cell as a CSS class.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Blank => "blank",
+            Self::Number => "number",
+            Self::Part => "part",
+            Self::Symbol => "symbol",
+            Self::Gear => "gear",
+        }
+    }
+
+    fn ansi(self) -> &'static str {
+        match self {
+            Self::Blank => "\x1b[2m",
+            Self::Number => "\x1b[31m",
+            Self::Part => "\x1b[32m",
+            Self::Symbol => "\x1b[33m",
+            Self::Gear => "\x1b[1;35m",
+        }
+    }
+
+    fn color(self) -> &'static str {
+        match self {
+            Self::Blank => "#999999",
+            Self::Number => "#cc3333",
+            Self::Part => "#33aa33",
+            Self::Symbol => "#cc9900",
+            Self::Gear => "#aa33cc",
+        }
+    }
+}
+
+/// How [`render`] draws a schematic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Render {
+    /// Text with ANSI colors for a terminal.
+    Ansi,
+    Svg,
+    Html,
+}
+
+impl Render {
+    pub const ALL: [Render; 3] = [Render::Ansi, Render::Svg, Render::Html];
+}
+
+impl Display for Render {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Ansi => "ansi",
+            Self::Svg => "svg",
+            Self::Html => "html",
+        })
+    }
+}
+
+impl FromStr for Render {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|render| render.to_string() == s)
+            .ok_or_else(|| format!("'{s}' is not one of ansi, svg or html"))
+    }
+}
+
+/// What every cell of `schematic` is according to `index`.
+pub fn classify(schematic: &Schematic, index: &PartIndex) -> Grid<Cell> {
+    let mut cells = schematic.cells().map(|_| Cell::Blank);
+    for number in index.numbers() {
+        let cell = if number.is_part() {
+            Cell::Part
+        } else {
+            Cell::Number
+        };
+        for column in number.columns.clone() {
+            cells[(number.row, column)] = cell;
+        }
+    }
+    for symbol in index.symbols() {
+        cells[(symbol.row, symbol.column)] = Cell::Symbol;
+    }
+    for gear in index.gears() {
+        cells[(gear.symbol.row, gear.symbol.column)] = Cell::Gear;
+    }
+    cells
+}
+
+/// Runs of cells of the same kind in every row, with their text.
+fn runs<'a>(
+    schematic: &'a Schematic,
+    cells: &'a Grid<Cell>,
+) -> impl Iterator<Item = Vec<(Cell, String)>> + 'a {
+    schematic
+        .cells()
+        .rows()
+        .zip(cells.rows())
+        .map(|(bytes, cells)| {
+            let mut runs: Vec<(Cell, String)> = Vec::new();
+            for (&byte, &cell) in bytes.iter().zip(cells.iter()) {
+                match runs.last_mut() {
+                    Some((last, text)) if *last == cell => text.push(char::from(byte)),
+                    _ => runs.push((cell, char::from(byte).to_string())),
+                }
+            }
+            runs
+        })
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Draws `schematic` with the numbers and symbols colored by what they are.
+pub fn render(schematic: &Schematic, index: &PartIndex, render: Render) -> String {
+    let cells = classify(schematic, index);
+    let mut out = String::new();
+    match render {
+        Render::Ansi => {
+            for row in runs(schematic, &cells) {
+                for (cell, text) in row {
+                    let _ = write!(out, "{}{text}\x1b[0m", cell.ansi());
+                }
+                out.push('\n');
+            }
+        }
+        Render::Svg => {
+            let _ = writeln!(
+                out,
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="14">"#,
+                schematic.width() * 9 + 8,
+                schematic.height() * 16 + 8
+            );
+            for (row, runs) in runs(schematic, &cells).enumerate() {
+                let _ = write!(
+                    out,
+                    r#"<text x="4" y="{}" xml:space="preserve">"#,
+                    row * 16 + 16
+                );
+                for (cell, text) in runs {
+                    let _ = write!(
+                        out,
+                        r#"<tspan class="{}" fill="{}">{}</tspan>"#,
+                        cell.name(),
+                        cell.color(),
+                        escape(&text)
+                    );
+                }
+                out.push_str("</text>\n");
+            }
+            out.push_str("</svg>\n");
+        }
+        Render::Html => {
+            out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Day 3</title>\n<style>\n");
+            for cell in Cell::ALL {
+                let _ = writeln!(out, ".{} {{ color: {}; }}", cell.name(), cell.color());
+            }
+            out.push_str(".gear { font-weight: bold; }\n</style>\n</head>\n<body>\n<pre>");
+            for row in runs(schematic, &cells) {
+                for (cell, text) in row {
+                    let _ = write!(
+                        out,
+                        r#"<span class="{}">{}</span>"#,
+                        cell.name(),
+                        escape(&text)
+                    );
+                }
+                out.push('\n');
+            }
+            out.push_str("</pre>\n</body>\n</html>\n");
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_cells() {
+        let schematic = Schematic::parse("467..114..\n...*......\n..35..633.\n").unwrap();
+        let index = PartIndex::new(&schematic).unwrap();
+        let cells = classify(&schematic, &index);
+        assert_eq!(cells[(0, 0)], Cell::Part);
+        assert_eq!(cells[(0, 5)], Cell::Number);
+        assert_eq!(cells[(1, 3)], Cell::Gear);
+        assert_eq!(cells[(1, 0)], Cell::Blank);
+
+        let html = render(&schematic, &index, Render::Html);
+        assert!(html.contains(r#"<span class="part">467</span><span class="blank">..</span>"#));
+        let ansi = render(&schematic, &index, Render::Ansi);
+        assert!(ansi.starts_with("\x1b[32m467\x1b[0m"));
+    }
+}
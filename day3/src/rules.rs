@@ -0,0 +1,180 @@
+use common::Connectivity;
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Which bytes of the schematic are symbols.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Symbols {
+    /// Any ASCII punctuation that is not a blank.
+    #[default]
+    Punctuation,
+    /// Only these bytes, unless they are blanks or digits.
+    Only(Vec<u8>),
+}
+
+/// Which cells count as next to a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The neighbors of any of the number's digits.
+    Adjacent(Connectivity),
+    /// Any cell at most this many rows and columns away from a digit, which
+    /// is further than a [`Connectivity`] reaches.
+    Radius(usize),
+}
+
+/// Diagonals count, as in the puzzle.
+impl Default for Neighborhood {
+    fn default() -> Self {
+        Self::Adjacent(Connectivity::Eight)
+    }
+}
+
+impl Display for Neighborhood {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Adjacent(Connectivity::Four) => f.write_str("4"),
+            Self::Adjacent(Connectivity::Eight) => f.write_str("8"),
+            Self::Radius(radius) => write!(f, "radius:{radius}"),
+        }
+    }
+}
+
+impl FromStr for Neighborhood {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "4" => Ok(Self::Adjacent(Connectivity::Four)),
+            "8" => Ok(Self::Adjacent(Connectivity::Eight)),
+            _ => s
+                .strip_prefix("radius:")
+                .and_then(|radius| radius.parse().ok())
+                .map(Self::Radius)
+                .ok_or_else(|| format!("'{s}' is not one of 4, 8 or radius:<N>")),
+        }
+    }
+}
+
+/// What the schematic's bytes mean and which of them are neighbors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub symbols: Symbols,
+    /// Bytes that are neither digits nor symbols.
+    pub blanks: Vec<u8>,
+    pub neighborhood: Neighborhood,
+}
+
+/// The puzzle's rules: any punctuation but `.` is a symbol, diagonals count.
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            symbols: Symbols::default(),
+            blanks: vec![b'.'],
+            neighborhood: Neighborhood::default(),
+        }
+    }
+}
+
+impl Rules {
+    pub fn is_symbol(&self, cell: u8) -> bool {
+        if cell.is_ascii_digit() || self.blanks.contains(&cell) {
+            return false;
+        }
+        match &self.symbols {
+            Symbols::Punctuation => cell.is_ascii_punctuation(),
+            Symbols::Only(symbols) => symbols.contains(&cell),
+        }
+    }
+}
+
+/// How the numbers next to a symbol are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Sum,
+    Product,
+    Count,
+}
+
+impl Aggregate {
+    pub const ALL: [Aggregate; 3] = [Aggregate::Sum, Aggregate::Product, Aggregate::Count];
+
+    /// Combines `values`, `None` if the result does not fit into 64 bits.
+    pub fn apply(self, values: impl IntoIterator<Item = u32>) -> Option<u64> {
+        let mut values = values.into_iter().map(u64::from);
+        match self {
+            Self::Sum => values.try_fold(0u64, |sum, value| sum.checked_add(value)),
+            Self::Product => values.try_fold(1u64, |product, value| product.checked_mul(value)),
+            Self::Count => Some(values.count() as u64),
+        }
+    }
+}
+
+impl Display for Aggregate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Sum => "sum",
+            Self::Product => "product",
+            Self::Count => "count",
+        })
+    }
+}
+
+impl FromStr for Aggregate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|aggregate| aggregate.to_string() == s)
+            .ok_or_else(|| format!("'{s}' is not one of sum, product or count"))
+    }
+}
+
+/// Combines the numbers next to every symbol that matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolQuery {
+    /// Only this symbol, or any.
+    pub symbol: Option<char>,
+    /// Only symbols next to exactly this many numbers, or any number of them.
+    pub numbers: Option<usize>,
+    pub aggregate: Aggregate,
+}
+
+impl SymbolQuery {
+    /// The query part 2 sums: the product of the two numbers next to a `*`.
+    pub const GEAR_RATIOS: SymbolQuery = SymbolQuery {
+        symbol: Some('*'),
+        numbers: Some(2),
+        aggregate: Aggregate::Product,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighborhoods() {
+        assert_eq!("4".parse(), Ok(Neighborhood::Adjacent(Connectivity::Four)));
+        assert_eq!(Neighborhood::default().to_string(), "8");
+        assert_eq!("radius:2".parse(), Ok(Neighborhood::Radius(2)));
+        assert!("6".parse::<Neighborhood>().is_err());
+    }
+
+    #[test]
+    fn symbols_and_aggregates() {
+        let rules = Rules {
+            symbols: Symbols::Only(b"*#.".to_vec()),
+            ..Rules::default()
+        };
+        assert!(rules.is_symbol(b'#'));
+        assert!(!rules.is_symbol(b'.'));
+        assert!(!rules.is_symbol(b'+'));
+        assert!(Rules::default().is_symbol(b'+'));
+        assert_eq!(Aggregate::Product.apply([3, 4, 5]), Some(60));
+        assert_eq!(Aggregate::Count.apply([3, 4, 5]), Some(3));
+        assert_eq!(Aggregate::Product.apply([u32::MAX; 3]), None);
+    }
+}
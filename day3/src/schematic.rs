@@ -0,0 +1,59 @@
+use common::{Grid, Result};
+
+/// The engine schematic as a grid of bytes, one row per line of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    cells: Grid<u8>,
+}
+
+impl Schematic {
+    /// Reads the schematic, taking its width from the first line. Every other
+    /// line must be just as long.
+    pub fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            cells: Grid::parse(input)?,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn cells(&self) -> &Grid<u8> {
+        &self.cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Error;
+
+    #[test]
+    fn infers_the_size() {
+        let schematic = Schematic::parse("467..\n...*.\n..35.\n").unwrap();
+        assert_eq!((schematic.width(), schematic.height()), (5, 3));
+        assert_eq!(schematic.cells()[(1, 3)], b'*');
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert_eq!(
+            Schematic::parse("467..\n...*\n..35.\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 5: found end of line, expected a row of 5 characters"
+        );
+        assert_eq!(
+            Schematic::parse("467..\n..35.\n...*..#\n")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 6: found '.#', expected a row of 5 characters"
+        );
+        assert!(matches!(Schematic::parse(""), Err(Error::Invalid(_))));
+    }
+}
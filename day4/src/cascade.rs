@@ -0,0 +1,186 @@
+use crate::Card;
+use common::{Error, Result};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// What to do with a card that wins copies of cards past the end of the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutOfRange {
+    /// Only win copies of the cards that exist.
+    Clamp,
+    /// Fail, naming the card.
+    #[default]
+    Error,
+}
+
+impl OutOfRange {
+    pub const ALL: [OutOfRange; 2] = [OutOfRange::Clamp, OutOfRange::Error];
+}
+
+impl Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Clamp => "clamp",
+            Self::Error => "error",
+        })
+    }
+}
+
+impl FromStr for OutOfRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|policy| policy.to_string() == s)
+            .ok_or_else(|| format!("'{s}' is not one of clamp or error"))
+    }
+}
+
+/// How many copies of one card there are in the end and where they came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardCopies {
+    /// The 1-based card number.
+    pub card: usize,
+    /// The original and every copy won.
+    pub copies: u64,
+    /// The 1-based numbers of the cards that won copies of this one, with
+    /// how many each of them won.
+    pub won_from: Vec<(usize, u64)>,
+}
+
+/// One line of a trace, e.g. `card 3: 4 copies (1 original, 1 from card 1, 2 from card 2)`.
+impl Display for CardCopies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let noun = if self.copies == 1 { "copy" } else { "copies" };
+        write!(f, "card {}: {} {noun} (1 original", self.card, self.copies)?;
+        for (card, copies) in &self.won_from {
+            write!(f, ", {copies} from card {card}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// The cards after every card won copies of the ones below it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade {
+    copies: Vec<u64>,
+    /// The last card each card wins a copy of, as an index.
+    last_won: Vec<usize>,
+}
+
+impl Cascade {
+    /// Plays all cards. Every card wins a copy of the next `matches` cards for
+    /// every copy of itself.
+    pub fn simulate(cards: &[Card], policy: OutOfRange) -> Result<Self> {
+        let too_many = |index: usize| {
+            Error::invalid(format!(
+                "the number of copies of card {} does not fit into 64 bits",
+                index + 1
+            ))
+        };
+        let mut last_won = Vec::with_capacity(cards.len());
+        let mut copies = Vec::with_capacity(cards.len());
+        // instead of adding a card's copies to every card it wins, keep a
+        // running total of the copies being won and take a card's copies out
+        // again after the last card it wins
+        let mut expiring = vec![0u64; cards.len() + 1];
+        let mut won = 0u64;
+        for (index, card) in cards.iter().enumerate() {
+            let mut last = index + card.matches() as usize;
+            if last >= cards.len() {
+                match policy {
+                    OutOfRange::Clamp => last = cards.len() - 1,
+                    OutOfRange::Error => {
+                        return Err(Error::invalid(format!(
+                            "card {} wins copies of cards past the end of the table",
+                            index + 1
+                        )))
+                    }
+                }
+            }
+            won -= expiring[index];
+            let count = won.checked_add(1).ok_or_else(|| too_many(index))?;
+            if last > index {
+                won = won.checked_add(count).ok_or_else(|| too_many(index + 1))?;
+                expiring[last + 1] += count;
+            }
+            copies.push(count);
+            last_won.push(last);
+        }
+        Ok(Self { copies, last_won })
+    }
+
+    /// How many of every card there are, in the order of the cards.
+    pub fn copies(&self) -> &[u64] {
+        &self.copies
+    }
+
+    /// All cards together.
+    pub fn total(&self) -> Result<u64> {
+        self.copies
+            .iter()
+            .try_fold(0u64, |total, copies| total.checked_add(*copies))
+            .ok_or_else(|| Error::invalid("the number of cards does not fit into 64 bits"))
+    }
+
+    /// The copies of every card together with the cards that won them.
+    pub fn trace(&self) -> Vec<CardCopies> {
+        let mut won_from = vec![Vec::new(); self.copies.len()];
+        for (source, &last) in self.last_won.iter().enumerate() {
+            for sources in &mut won_from[source + 1..=last] {
+                sources.push((source + 1, self.copies[source]));
+            }
+        }
+        won_from
+            .into_iter()
+            .zip(&self.copies)
+            .enumerate()
+            .map(|(index, (won_from, &copies))| CardCopies {
+                card: index + 1,
+                copies,
+                won_from,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(matches: &[u32]) -> Vec<Card> {
+        matches
+            .iter()
+            .map(|&matches| Card::from(((1..=matches).collect(), (1..=matches).collect())))
+            .collect()
+    }
+
+    #[test]
+    fn traces_the_copies() {
+        let cascade = Cascade::simulate(&cards(&[4, 2, 2, 1, 0, 0]), OutOfRange::Error).unwrap();
+        assert_eq!(cascade.copies(), [1, 2, 4, 8, 14, 1]);
+        assert_eq!(cascade.total().unwrap(), 30);
+        let trace: Vec<_> = cascade.trace().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            trace[3],
+            "card 4: 8 copies (1 original, 1 from card 1, 2 from card 2, 4 from card 3)"
+        );
+        assert_eq!(trace[5], "card 6: 1 copy (1 original)");
+    }
+
+    #[test]
+    fn wins_past_the_end() {
+        let cards = cards(&[1, 3]);
+        assert_eq!(
+            Cascade::simulate(&cards, OutOfRange::Error)
+                .unwrap_err()
+                .to_string(),
+            "invalid puzzle: card 2 wins copies of cards past the end of the table"
+        );
+        let cascade = Cascade::simulate(&cards, OutOfRange::Clamp).unwrap();
+        assert_eq!(cascade.copies(), [1, 2]);
+    }
+}
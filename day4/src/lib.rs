@@ -0,0 +1,213 @@
+//! Day 4: Scratchcards.
+
+use common::{Error, Result, Solution};
+use nom::{
+    bytes::complete::*, character::complete::*, combinator::all_consuming, multi::*, sequence::*,
+    IResult,
+};
+
+mod cascade;
+mod numbers;
+
+pub use cascade::{CardCopies, Cascade, OutOfRange};
+pub use numbers::NumberSet;
+
+/// A scratchcard. The numbers are only set by [`Card::new`], so that the
+/// matches counted there stay correct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    winning_numbers: NumberSet,
+    my_numbers: NumberSet,
+    matches: u32,
+}
+
+impl From<(Vec<u32>, Vec<u32>)> for Card {
+    fn from(tuple: (Vec<u32>, Vec<u32>)) -> Self {
+        Self::new(NumberSet::new(tuple.0), NumberSet::new(tuple.1))
+    }
+}
+
+impl Card {
+    /// A card whose matches are counted once, up front.
+    pub fn new(winning_numbers: NumberSet, my_numbers: NumberSet) -> Self {
+        let matches = winning_numbers.intersection_len(&my_numbers) as u32;
+        Self {
+            winning_numbers,
+            my_numbers,
+            matches,
+        }
+    }
+
+    pub fn winning_numbers(&self) -> &NumberSet {
+        &self.winning_numbers
+    }
+
+    pub fn my_numbers(&self) -> &NumberSet {
+        &self.my_numbers
+    }
+
+    /// How many of my numbers are winning numbers.
+    pub fn matches(&self) -> u32 {
+        self.matches
+    }
+
+    /// The number of matches, or with `double` the points they are worth;
+    /// `None` if the points do not fit into 64 bits.
+    pub fn score(&self, double: bool) -> Option<u64> {
+        if self.matches > 0 && double {
+            1u64.checked_shl(self.matches - 1)
+        } else {
+            Some(u64::from(self.matches))
+        }
+    }
+}
+
+pub fn parse_card(input: &str) -> IResult<&str, u32> {
+    let (input, card_id) = terminated(
+        preceded(tuple((tag("Card"), many1(tag(" ")))), u32),
+        tag(":"),
+    )(input)?;
+    Ok((input, card_id))
+}
+
+pub fn parse_winning_numbers(input: &str) -> IResult<&str, Vec<u32>> {
+    let (input, winning_numbers) = terminated(
+        preceded(many0(tag(" ")), separated_list1(many1(tag(" ")), u32)),
+        tag(" | "),
+    )(input)?;
+    Ok((input, winning_numbers))
+}
+
+pub fn parse_numbers(input: &str) -> IResult<&str, Vec<u32>> {
+    let (input, numbers) = preceded(many0(tag(" ")), separated_list1(many1(tag(" ")), u32))(input)?;
+    Ok((input, numbers))
+}
+
+/// Parses a whole `Card 1: 41 48 | 83 86` line.
+pub fn parse_round(input: &str) -> IResult<&str, Card> {
+    let (input, parsed) =
+        preceded(parse_card, tuple((parse_winning_numbers, parse_numbers)))(input)?;
+    Ok((input, Card::from(parsed)))
+}
+
+pub fn part1(cards: &[Card]) -> Result<u64> {
+    let mut sum = 0u64;
+    for (index, card) in cards.iter().enumerate() {
+        sum = card
+            .score(true)
+            .and_then(|points| sum.checked_add(points))
+            .ok_or_else(|| {
+                Error::invalid(format!(
+                    "the points of card {} do not fit into 64 bits",
+                    index + 1
+                ))
+            })?;
+    }
+    Ok(sum)
+}
+
+/// Counts the cards after every card won copies of the ones below it.
+pub fn part2(all_cards: &[Card]) -> Result<u64> {
+    Cascade::simulate(all_cards, OutOfRange::Error)?.total()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(full: &str) -> Result<Self::Input<'_>> {
+        full.lines()
+            .map(|line| {
+                let (_, card) = all_consuming(parse_round)(line).map_err(|err| {
+                    Error::from_nom(full, err, "a card like `Card 1: 41 48 | 83 86`")
+                })?;
+                Ok(card)
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<u64> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn card_score() {
+        let cards = Day4::parse(EXAMPLE).unwrap();
+        let points: Vec<_> = cards.iter().map(|card| card.score(true)).collect();
+        assert_eq!(points, [8, 2, 2, 1, 0, 0].map(Some));
+        let matches: Vec<_> = cards.iter().map(|card| card.score(false)).collect();
+        assert_eq!(matches, [4, 2, 2, 1, 0, 0].map(Some));
+    }
+
+    #[test]
+    fn points_that_overflow_are_an_error() {
+        let numbers: Vec<u32> = (1..=64).collect();
+        let card = Card::from((numbers.clone(), numbers));
+        assert_eq!(card.score(false), Some(64));
+        assert_eq!(card.winning_numbers(), card.my_numbers());
+        assert_eq!(card.score(true), Some(1 << 63));
+        let cards = [card.clone(), card];
+        assert_eq!(
+            part1(&cards).unwrap_err().to_string(),
+            "invalid puzzle: the points of card 2 do not fit into 64 bits"
+        );
+
+        let numbers: Vec<u32> = (1..=65).collect();
+        assert_eq!(Card::from((numbers.clone(), numbers)).score(true), None);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input).unwrap(), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input).unwrap(), 30);
+    }
+
+    #[test]
+    fn malformed_card_is_an_error() {
+        let err = Day4::parse("Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 12: found 'x2', expected a card like `Card 1: 41 48 | 83 86`"
+        );
+        let err = Day4::parse("Card 1: 41 48 | 83 86 x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 23: found 'x', expected a card like `Card 1: 41 48 | 83 86`"
+        );
+    }
+
+    #[test]
+    fn any_line_ending() {
+        let cards = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::parse(EXAMPLE.trim_end()).unwrap(), cards);
+        assert_eq!(Day4::parse(&EXAMPLE.replace('\n', "\r\n")).unwrap(), cards);
+    }
+}
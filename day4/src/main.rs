@@ -1,96 +1,47 @@
-use anyhow::Result;
-use nom::{bytes::complete::*, character::complete::*, multi::*, sequence::*, IResult};
-use std::fs::read_to_string;
-
-#[derive(Debug)]
-struct Card {
-    winning_numbers: Vec<u32>,
-    my_numbers: Vec<u32>,
+use anyhow::{Context, Result};
+use clap::Parser;
+use common::{Format, InputSource, Inputs, Part, PartAnswer, Reporter, Solution};
+use day4::{Cascade, Day4, OutOfRange};
+
+#[derive(Parser)]
+#[command(about = "Day 4: Scratchcards")]
+struct Args {
+    /// Read the input from this file, `-` for stdin [default: day4/input.txt in the inputs directory]
+    input: Option<String>,
+    /// What to do with cards that win copies of cards past the end of the table: clamp or error
+    #[arg(long, default_value_t = OutOfRange::Error)]
+    out_of_range: OutOfRange,
+    /// Print how many copies of every card part 2 ends up with and which cards won them
+    #[arg(long)]
+    trace: bool,
 }
 
-impl From<(Vec<u32>, Vec<u32>)> for Card {
-    fn from(tuple: (Vec<u32>, Vec<u32>)) -> Self {
-        Self {
-            winning_numbers: tuple.0,
-            my_numbers: tuple.1,
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let source = args
+        .input
+        .as_deref()
+        .map_or(InputSource::Default, InputSource::from);
+    let input = Inputs::from_env()
+        .read(Day4::DAY, &source)
+        .context("Day 4")?;
+    let cards = Day4::parse(&input).context("Day 4")?;
+    let part1 = PartAnswer::timed(Part::One, || day4::part1(&cards)).context("Day 4")?;
+    let mut cascade = None;
+    let part2 = PartAnswer::timed(Part::Two, || {
+        cascade
+            .insert(Cascade::simulate(&cards, args.out_of_range)?)
+            .total()
+    })
+    .context("Day 4")?;
+    if let (true, Some(cascade)) = (args.trace, cascade) {
+        for card in cascade.trace() {
+            println!("{card}");
         }
     }
-}
-
-impl Card {
-    fn score(&self, double: bool) -> u32 {
-        let num_winning: u32 = self.winning_numbers.iter().fold(0u32, |acc, w| {
-            if self.my_numbers.contains(&w) {
-                acc + 1
-            } else {
-                acc
-            }
-        });
-        if num_winning > 0 && double {
-            1 << (num_winning - 1)
-        } else {
-            num_winning
-        }
+    let mut reporter = Reporter::stdout(Format::Text);
+    for answer in [part1, part2] {
+        reporter.report(Day4::DAY, &answer)?;
     }
-}
-
-fn parse_card(input: &str) -> IResult<&str, u32> {
-    let (input, card_id) = terminated(
-        preceded(tuple((tag("Card"), many1(tag(" ")))), u32),
-        tag(":"),
-    )(input)?;
-    Ok((input, card_id))
-}
-
-fn parse_winning_numbers(input: &str) -> IResult<&str, Vec<u32>> {
-    let (input, winning_numbers) = terminated(
-        preceded(many0(tag(" ")), separated_list1(many1(tag(" ")), u32)),
-        tag(" | "),
-    )(input)?;
-    Ok((input, winning_numbers))
-}
-
-fn parse_numbers(input: &str) -> IResult<&str, Vec<u32>> {
-    let (input, numbers) = terminated(
-        preceded(many0(tag(" ")), separated_list1(many1(tag(" ")), u32)),
-        tag("\n"),
-    )(input)?;
-    Ok((input, numbers))
-}
-
-fn parse_round(input: &str) -> IResult<&str, Card> {
-    let (input, parsed) = preceded(parse_card, tuple((parse_winning_numbers, parse_numbers)))(input)?;
-    Ok((input, Card::from(parsed)))
-}
-
-fn part1(mut input: &str) {
-    let mut sum = 0;
-    while let Ok((input1, round)) = parse_round(input) {
-        sum += round.score(true);
-        input = input1;
-    }
-    println!("Day 4 Part 1: {sum}");
-}
-
-fn part2(mut input: &str) {
-    let mut all_cards: Vec<Card> = vec![];
-    while let Ok((input1, card)) = parse_round(input) {
-        all_cards.push(card);
-        input = input1;
-    }
-    let mut card_counts = vec![1; all_cards.len()];
-    for (index, card) in all_cards.iter().enumerate() {
-        let score = card.score(false);
-            for i in index + 1..=index + score as usize {
-                card_counts[i] += card_counts[index];
-            }
-    }
-    println!("Day 4 Part 2: {}", card_counts.iter().sum::<u32>());
-}
-
-fn main() -> Result<()> {
-    let mut input = read_to_string("input.txt")?;
-    part1(input.clone().as_mut_str());
-    part2(input.as_mut_str());
     Ok(())
 }
@@ -0,0 +1,116 @@
+/// A set of the numbers on a card.
+///
+/// Numbers below 128, which are all the puzzle uses, are kept in a bitset, so
+/// that a card's matches are a single `AND`. Sets with larger numbers fall back
+/// to a sorted vector.
+#[derive(Debug, Clone)]
+pub struct NumberSet(Repr);
+
+#[derive(Debug, Clone)]
+enum Repr {
+    Small(u128),
+    /// Sorted and without duplicates.
+    Sorted(Vec<u32>),
+}
+
+impl NumberSet {
+    pub fn new(numbers: impl IntoIterator<Item = u32>) -> Self {
+        let mut numbers: Vec<_> = numbers.into_iter().collect();
+        if numbers.iter().all(|&number| number < u128::BITS) {
+            return Self(Repr::Small(
+                numbers
+                    .into_iter()
+                    .fold(0, |bits, number| bits | 1 << number),
+            ));
+        }
+        numbers.sort_unstable();
+        numbers.dedup();
+        Self(Repr::Sorted(numbers))
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Repr::Small(bits) => bits.count_ones() as usize,
+            Repr::Sorted(numbers) => numbers.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(&self, number: u32) -> bool {
+        match &self.0 {
+            Repr::Small(bits) => number < u128::BITS && bits & 1 << number != 0,
+            Repr::Sorted(numbers) => numbers.binary_search(&number).is_ok(),
+        }
+    }
+
+    /// How many numbers are in both sets.
+    pub fn intersection_len(&self, other: &NumberSet) -> usize {
+        match (&self.0, &other.0) {
+            (Repr::Small(a), Repr::Small(b)) => (a & b).count_ones() as usize,
+            (Repr::Small(bits), Repr::Sorted(numbers))
+            | (Repr::Sorted(numbers), Repr::Small(bits)) => numbers
+                .iter()
+                .take_while(|&&number| number < u128::BITS)
+                .filter(|&&number| bits & 1 << number != 0)
+                .count(),
+            (Repr::Sorted(a), Repr::Sorted(b)) => {
+                let (mut i, mut j, mut count) = (0, 0, 0);
+                while i < a.len() && j < b.len() {
+                    match a[i].cmp(&b[j]) {
+                        std::cmp::Ordering::Less => i += 1,
+                        std::cmp::Ordering::Greater => j += 1,
+                        std::cmp::Ordering::Equal => {
+                            count += 1;
+                            i += 1;
+                            j += 1;
+                        }
+                    }
+                }
+                count
+            }
+        }
+    }
+}
+
+/// Sets are equal if they hold the same numbers, however they are stored.
+impl PartialEq for NumberSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.intersection_len(other) == self.len()
+    }
+}
+
+impl Eq for NumberSet {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersections() {
+        let small = NumberSet::new([41, 48, 83, 86, 17]);
+        let mine = NumberSet::new([83, 86, 6, 31, 17, 9, 48, 53]);
+        assert!(matches!(small.0, Repr::Small(_)));
+        assert_eq!(small.intersection_len(&mine), 4);
+
+        let large = NumberSet::new([1_000_000, 17, 83, 17]);
+        assert!(matches!(&large.0, Repr::Sorted(numbers) if numbers == &[17, 83, 1_000_000]));
+        assert_eq!(large.intersection_len(&small), 2);
+        assert_eq!(small.intersection_len(&large), 2);
+        let other = NumberSet::new([83, 1_000_000, 2_000_000]);
+        assert_eq!(large.intersection_len(&other), 2);
+        assert!(large.contains(1_000_000));
+        assert!(!small.contains(1_000_000));
+    }
+
+    #[test]
+    fn equal_by_contents() {
+        assert_eq!(NumberSet::new([3, 1, 3]), NumberSet::new([1, 3]));
+        assert_ne!(NumberSet::new([1, 3]), NumberSet::new([1, 3, 1_000_000]));
+        let sorted = NumberSet(Repr::Sorted(vec![1, 3]));
+        assert_eq!(sorted, NumberSet::new([1, 3]));
+        assert_eq!(NumberSet::new([1, 3]), sorted);
+    }
+}
@@ -0,0 +1,277 @@
+//! Day 5: If You Give A Seed A Fertilizer.
+
+use common::{Error, Result, Solution};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{newline, one_of, space1, u64},
+    multi::{many1, separated_list1},
+    sequence::preceded,
+    IResult,
+};
+use rayon::prelude::*;
+
+/// The headers of the maps, in the order they are applied.
+pub static STRINGS: [&str; 7] = [
+    "seed-to-soil map:\n",
+    "soil-to-fertilizer map:\n",
+    "fertilizer-to-water map:\n",
+    "water-to-light map:\n",
+    "light-to-temperature map:\n",
+    "temperature-to-humidity map:\n",
+    "humidity-to-location map:\n",
+];
+
+pub type PResult<'a, T> = IResult<&'a str, T>;
+
+pub fn parse_seeds(input: &str) -> PResult<'_, Vec<u64>> {
+    preceded(tag("seeds: "), separated_list1(space1, u64))(input)
+}
+
+pub fn parse_map_nums(input: &str) -> PResult<'_, Vec<u64>> {
+    separated_list1(one_of(" \n"), u64)(input)
+}
+
+pub fn parse_map<'a>(input: &'a str, map_str: &'a str) -> PResult<'a, Vec<u64>> {
+    preceded(many1(newline), preceded(tag(map_str), parse_map_nums))(input)
+}
+
+pub fn parse_almanac<'a>(input: &'a str) -> PResult<'a, Almanac> {
+    let (mut input, seeds) = parse_seeds(input)?;
+    let mut maps: Vec<Vec<u64>> = Vec::with_capacity(STRINGS.len());
+    for map_str in STRINGS {
+        let (input1, map) = parse_map(input, map_str)?;
+        maps.push(map);
+        input = input1;
+    }
+
+    Ok((input, Almanac::from((seeds, maps))))
+}
+
+#[derive(Default, Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    /// Every map as `[destination start, source start, length]` ranges.
+    pub maps: Vec<Vec<Vec<u64>>>,
+}
+
+impl Almanac {
+    /// Maps a seed through every map to its location.
+    pub fn transform(&self, mut input: u64) -> u64 {
+        for map in &self.maps {
+            if let Some(pos) = map
+                .iter()
+                .position(|x| (x[1]..(x[1] + x[2])).contains(&input))
+            {
+                let offset = input - map[pos][1];
+                input = map[pos][0] + offset;
+            }
+        }
+        input
+    }
+    pub fn transform_slice(&self, input: &mut [u64]) {
+        input.par_iter_mut().for_each(|e| *e = self.transform(*e));
+    }
+}
+
+impl From<(Vec<u64>, Vec<Vec<u64>>)> for Almanac {
+    fn from((seeds, maps): (Vec<u64>, Vec<Vec<u64>>)) -> Self {
+        let maps: Vec<Vec<Vec<u64>>> = maps
+            .into_iter()
+            .map(|map| {
+                map.into_iter()
+                    .chunks(3)
+                    .into_iter()
+                    .map(|chunk| chunk.collect())
+                    .collect()
+            })
+            .collect();
+        Almanac { seeds, maps }
+    }
+}
+
+pub fn part1(almanac: &Almanac) -> Result<u64> {
+    let transformed: Vec<u64> = almanac
+        .seeds
+        .iter()
+        .map(|x| almanac.transform(*x))
+        .collect();
+    transformed
+        .iter()
+        .min()
+        .copied()
+        .ok_or_else(|| Error::invalid("there are no seeds"))
+}
+
+/// Reads the seeds as pairs of range start and length.
+pub fn part2(almanac: &Almanac) -> Result<u64> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(Error::invalid(
+            "the seeds are not pairs of range start and length",
+        ));
+    }
+    let mut global_min: Option<u64> = None;
+    for seeds in almanac.seeds.chunks(2) {
+        let end = seeds[0].checked_add(seeds[1]).ok_or_else(|| {
+            Error::invalid(format!(
+                "the seed range starting at {} goes past the largest number",
+                seeds[0]
+            ))
+        })?;
+        let mut range: Vec<u64> = (seeds[0]..end).collect();
+        almanac.transform_slice(&mut range);
+        if let Some(min) = range.par_iter().min() {
+            if global_min.is_none() || min < &global_min.unwrap() {
+                global_min = Some(*min);
+            }
+        }
+    }
+    global_min.ok_or_else(|| Error::invalid("there are no seeds"))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(full: &str) -> Result<Self::Input<'_>> {
+        let (input, almanac) = parse_almanac(full)
+            .map_err(|err| Error::from_nom(full, err, "the seeds or the next map"))?;
+        if !input.trim().is_empty() {
+            return Err(Error::parse(full, input.trim_start(), "end of input"));
+        }
+        if let Some((index, _)) = almanac
+            .maps
+            .iter()
+            .enumerate()
+            .find(|(_, map)| map.iter().any(|range| range.len() != 3))
+        {
+            return Err(Error::invalid(format!(
+                "the ranges of the {} are not triples of numbers",
+                STRINGS[index].trim_end_matches(":\n")
+            )));
+        }
+        // `transform` adds the length to both starts
+        for (index, map) in almanac.maps.iter().enumerate() {
+            if let Some(range) = map.iter().find(|range| {
+                range[0].checked_add(range[2]).is_none() || range[1].checked_add(range[2]).is_none()
+            }) {
+                return Err(Error::invalid(format!(
+                    "the range {} {} {} of the {} goes past the largest number",
+                    range[0],
+                    range[1],
+                    range[2],
+                    STRINGS[index].trim_end_matches(":\n")
+                )));
+            }
+        }
+        Ok(almanac)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<u64> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn parses_almanac() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(almanac.seeds, [79, 14, 55, 13]);
+        assert_eq!(almanac.maps.len(), 7);
+        assert_eq!(almanac.maps[0], [[50, 98, 2], [52, 50, 48]]);
+    }
+
+    #[test]
+    fn transform() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let locations: Vec<_> = almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.transform(*seed))
+            .collect();
+        assert_eq!(locations, [82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input).unwrap(), 35);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input).unwrap(), 46);
+    }
+
+    #[test]
+    fn incomplete_range_is_an_error() {
+        let input = EXAMPLE.replace("56 93 4", "56 93");
+        assert_eq!(
+            Day5::parse(&input).unwrap_err().to_string(),
+            "invalid puzzle: the ranges of the humidity-to-location map are not triples of numbers"
+        );
+    }
+
+    #[test]
+    fn ranges_past_the_largest_number_are_an_error() {
+        let input = EXAMPLE.replace("56 93 4", "56 18446744073709551615 4");
+        assert_eq!(
+            Day5::parse(&input).unwrap_err().to_string(),
+            "invalid puzzle: the range 56 18446744073709551615 4 of the humidity-to-location map goes past the largest number"
+        );
+        let input = EXAMPLE.replace("79 14 55 13", "79 14 18446744073709551615 1");
+        let almanac = Day5::parse(&input).unwrap();
+        assert_eq!(
+            Day5::part2(&almanac).unwrap_err().to_string(),
+            "invalid puzzle: the seed range starting at 18446744073709551615 goes past the largest number"
+        );
+    }
+}
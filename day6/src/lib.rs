@@ -0,0 +1,198 @@
+//! Day 6: Wait For It. Toy boat races.
+
+use common::{Error, Result, Solution};
+use std::num::{IntErrorKind, ParseIntError};
+
+pub static ACC: usize = 1; // 1mm/s /s
+
+pub struct Race {
+    pub time: usize,
+    /// The record to beat.
+    pub distance: usize,
+}
+
+impl Race {
+    /// Whether holding the button for `button_time` beats the record; tying
+    /// it is not a win.
+    pub fn is_win(&self, button_time: usize) -> bool {
+        let final_speed = ACC * button_time;
+        let remaining_time = self.time - button_time;
+        // a distance too large for a usize beats any record
+        final_speed
+            .checked_mul(remaining_time)
+            .is_none_or(|distance_traveled| distance_traveled > self.distance)
+    }
+
+    /// How many button times beat the record.
+    pub fn number_of_wins(&self) -> usize {
+        let mut smallest = None;
+        let mut biggest = None;
+        for i in 0..=self.time {
+            if smallest.is_none() && self.is_win(i) {
+                smallest = Some(i);
+            }
+            if self.is_win(i) {
+                biggest = Some(i);
+            }
+        }
+        match (smallest, biggest) {
+            (Some(smallest), Some(biggest)) => biggest - smallest + 1,
+            _ => 0,
+        }
+    }
+}
+
+/// Splits the numbers off a line like `Time:  7  15   30`.
+pub fn parse_line<'a>(input: &'a str, line: Option<&'a str>, name: &str) -> Result<Vec<&'a str>> {
+    let expected = || format!("a line starting with '{name}:'");
+    let line = line.ok_or_else(|| Error::parse(input, &input[input.len()..], expected()))?;
+    let numbers = line
+        .strip_prefix(name)
+        .and_then(|line| line.strip_prefix(':'))
+        .ok_or_else(|| Error::parse(input, line, expected()))?;
+    let numbers: Vec<_> = numbers.split_whitespace().collect();
+    if numbers.is_empty() {
+        return Err(Error::parse(input, &line[line.len()..], "a number"));
+    }
+    for number in &numbers {
+        if !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::parse(input, number, "a number"));
+        }
+    }
+    Ok(numbers)
+}
+
+/// Parses a run of digits checked by [`parse_line`], which never returns an
+/// empty one, so this can only fail by overflowing.
+pub fn parse_number(digits: &str) -> Result<usize> {
+    digits
+        .parse()
+        .map_err(|err: ParseIntError| match err.kind() {
+            IntErrorKind::PosOverflow => Error::invalid(format!("{digits} is too large")),
+            _ => Error::invalid(format!("'{digits}' is not a number")),
+        })
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    /// The time and distance columns, still as text so part 2 can join their digits.
+    type Input<'a> = Vec<(&'a str, &'a str)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut lines = input.lines();
+        let times = parse_line(input, lines.next(), "Time")?;
+        let distances = parse_line(input, lines.next(), "Distance")?;
+        if times.len() != distances.len() {
+            return Err(Error::invalid(format!(
+                "there are {} times but {} distances",
+                times.len(),
+                distances.len()
+            )));
+        }
+        Ok(times.into_iter().zip(distances).collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize> {
+        let mut product = 1usize;
+        for (time, distance) in input {
+            let race = Race {
+                time: parse_number(time)?,
+                distance: parse_number(distance)?,
+            };
+            product = product.checked_mul(race.number_of_wins()).ok_or_else(|| {
+                Error::invalid(format!(
+                    "the product of the wins does not fit into {} bits",
+                    usize::BITS
+                ))
+            })?;
+        }
+        Ok(product)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<usize> {
+        let (time, distance): (String, String) = input.iter().copied().unzip();
+        let race = Race {
+            time: parse_number(&time)?,
+            distance: parse_number(&distance)?,
+        };
+        Ok(race.number_of_wins())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn number_of_wins() {
+        let wins: Vec<_> = [(7, 9), (15, 40), (30, 200)]
+            .into_iter()
+            .map(|(time, distance)| Race { time, distance }.number_of_wins())
+            .collect();
+        assert_eq!(wins, [4, 8, 9]);
+    }
+
+    #[test]
+    fn tying_the_record_is_not_a_win() {
+        // holding the button for 10 of 30 ms travels exactly the 200 mm record
+        let race = Race {
+            time: 30,
+            distance: 200,
+        };
+        assert!(!race.is_win(10));
+        assert!(race.is_win(11));
+    }
+
+    #[test]
+    fn distances_past_the_largest_number_win() {
+        let race = Race {
+            time: usize::MAX,
+            distance: usize::MAX,
+        };
+        assert!(race.is_win(usize::MAX / 2));
+        assert!(!race.is_win(usize::MAX));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input).unwrap(), 288);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input).unwrap(), 71503);
+    }
+
+    #[test]
+    fn malformed_number_is_an_error() {
+        assert_eq!(
+            Day6::parse("Time: 7 15\nDistance: 9 4O\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 13: found '4O', expected a number"
+        );
+    }
+
+    #[test]
+    fn row_without_numbers_is_an_error() {
+        assert_eq!(
+            Day6::parse("Time:\nDistance:\n").unwrap_err().to_string(),
+            "line 1, column 6: found end of line, expected a number"
+        );
+        assert_eq!(
+            parse_number("").unwrap_err().to_string(),
+            "invalid puzzle: '' is not a number"
+        );
+    }
+}
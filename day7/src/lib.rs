@@ -0,0 +1,383 @@
+//! Day 7: Camel Cards.
+
+use common::{Error, Result, Solution};
+use std::{collections::BTreeMap, str::FromStr};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    A,
+}
+
+impl TryFrom<char> for Card {
+    type Error = char;
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Card::Two),
+            '3' => Ok(Card::Three),
+            '4' => Ok(Card::Four),
+            '5' => Ok(Card::Five),
+            '6' => Ok(Card::Six),
+            '7' => Ok(Card::Seven),
+            '8' => Ok(Card::Eight),
+            '9' => Ok(Card::Nine),
+            'T' => Ok(Card::Ten),
+            'J' => Ok(Card::Jack),
+            'Q' => Ok(Card::Queen),
+            'K' => Ok(Card::King),
+            'A' => Ok(Card::A),
+            _ => Err(value),
+        }
+    }
+}
+
+/// A hand ordered by its type first and its cards second.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum HandType {
+    High(Vec<Card>),
+    Pair(Vec<Card>),
+    TwoPair(Vec<Card>),
+    Three(Vec<Card>),
+    FullHouse(Vec<Card>),
+    Four(Vec<Card>),
+    Five(Vec<Card>),
+}
+
+impl FromStr for HandType {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hand: Vec<Card> = s
+            .chars()
+            .map(Card::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(|_| "invalid card")?;
+        if hand.len() != 5 {
+            return Err("a hand has five cards");
+        }
+        let mut counts: BTreeMap<Card, usize> = BTreeMap::new();
+        for c in hand.iter() {
+            if let Some(acc) = counts.get_mut(c) {
+                *acc += 1;
+            } else {
+                counts.insert(*c, 1);
+            }
+        }
+        let mut hand_type: Vec<usize> = counts.into_iter().map(|x| x.1).collect();
+        hand_type.sort();
+        match &hand_type[..] {
+            [5] => Ok(HandType::Five(hand)),
+            [1, 4] => Ok(HandType::Four(hand)),
+            [2, 3] => Ok(HandType::FullHouse(hand)),
+            [1, 1, 3] => Ok(HandType::Three(hand)),
+            [1, 2, 2] => Ok(HandType::TwoPair(hand)),
+            [1, 1, 1, 2] => Ok(HandType::Pair(hand)),
+            _ => Ok(HandType::High(hand)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    pub hand: HandType,
+    pub bet: usize,
+}
+
+/// A card of part 2, where jacks are jokers worth the least.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Card2 {
+    Jack,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Queen,
+    King,
+    A,
+}
+
+impl TryFrom<char> for Card2 {
+    type Error = char;
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Self::Two),
+            '3' => Ok(Self::Three),
+            '4' => Ok(Self::Four),
+            '5' => Ok(Self::Five),
+            '6' => Ok(Self::Six),
+            '7' => Ok(Self::Seven),
+            '8' => Ok(Self::Eight),
+            '9' => Ok(Self::Nine),
+            'T' => Ok(Self::Ten),
+            'J' => Ok(Self::Jack),
+            'Q' => Ok(Self::Queen),
+            'K' => Ok(Self::King),
+            'A' => Ok(Self::A),
+            _ => Err(value),
+        }
+    }
+}
+
+/// A hand of part 2, whose jokers count as whatever card makes it strongest.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum HandType2 {
+    High(Vec<Card2>),
+    Pair(Vec<Card2>),
+    TwoPair(Vec<Card2>),
+    Three(Vec<Card2>),
+    FullHouse(Vec<Card2>),
+    Four(Vec<Card2>),
+    Five(Vec<Card2>),
+}
+
+impl FromStr for HandType2 {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hand: Vec<Card2> = s
+            .chars()
+            .map(Card2::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(|_| "invalid card")?;
+        if hand.len() != 5 {
+            return Err("a hand has five cards");
+        }
+        let mut counts: BTreeMap<Card2, usize> = BTreeMap::new();
+        for c in hand.iter() {
+            if let Some(acc) = counts.get_mut(c) {
+                *acc += 1;
+            } else {
+                counts.insert(*c, 1);
+            }
+        }
+        let mut hand_type: Vec<usize> = counts.into_iter().map(|x| x.1).collect();
+        hand_type.sort();
+        let num_jacks: usize = hand
+            .iter()
+            .map(|x| match x {
+                Card2::Jack => 1,
+                _ => 0,
+            })
+            .sum();
+        match &hand_type[..] {
+            [5] => Ok(Self::Five(hand)),
+            [1, 4] => {
+                if num_jacks == 1 || num_jacks == 4 {
+                    Ok(Self::Five(hand))
+                } else {
+                    Ok(Self::Four(hand))
+                }
+            }
+            [2, 3] => {
+                if num_jacks == 2 || num_jacks == 3 {
+                    Ok(Self::Five(hand))
+                } else {
+                    Ok(Self::FullHouse(hand))
+                }
+            }
+            [1, 1, 3] => {
+                if num_jacks == 1 || num_jacks == 3 {
+                    Ok(Self::Four(hand))
+                } else {
+                    Ok(Self::Three(hand))
+                }
+            }
+            [1, 2, 2] => {
+                if num_jacks == 1 {
+                    Ok(Self::FullHouse(hand))
+                } else if num_jacks == 2 {
+                    Ok(Self::Four(hand))
+                } else {
+                    Ok(Self::TwoPair(hand))
+                }
+            }
+            [1, 1, 1, 2] => {
+                if num_jacks == 1 || num_jacks == 2 {
+                    Ok(Self::Three(hand))
+                } else {
+                    Ok(Self::Pair(hand))
+                }
+            }
+            _ => {
+                if num_jacks == 1 {
+                    Ok(Self::Pair(hand))
+                } else {
+                    Ok(Self::High(hand))
+                }
+            }
+        }
+    }
+}
+#[derive(Debug)]
+pub struct Hand2 {
+    pub hand: HandType2,
+    pub bet: usize,
+}
+
+/// Splits every line into its hand and bet, making sure both are well-formed.
+pub fn parse_hands(input: &str) -> Result<Vec<(&str, usize)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (hand, bet) = line
+                .split_once(' ')
+                .ok_or_else(|| Error::parse(input, &line[line.len()..], "a space and a bet"))?;
+            if let Some(card) = hand.find(|c| Card::try_from(c).is_err()) {
+                return Err(Error::parse(
+                    input,
+                    &hand[card..],
+                    "a card out of 23456789TJQKA",
+                ));
+            }
+            if hand.len() != 5 {
+                return Err(Error::parse(input, hand, "a hand of five cards"));
+            }
+            let bet = bet.parse().map_err(|_| Error::parse(input, bet, "a bet"))?;
+            Ok((hand, bet))
+        })
+        .collect()
+}
+
+/// Sums every bet times its rank, given the bets from the weakest hand up.
+fn total_winnings(bets: impl Iterator<Item = usize>) -> Result<usize> {
+    let mut sum = 0usize;
+    for (rank, bet) in (1usize..).zip(bets) {
+        sum = rank
+            .checked_mul(bet)
+            .and_then(|winnings| sum.checked_add(winnings))
+            .ok_or_else(|| {
+                Error::invalid(format!(
+                    "the winnings of rank {rank} do not fit into {} bits",
+                    usize::BITS
+                ))
+            })?;
+    }
+    Ok(sum)
+}
+
+pub fn part1(input: &[(&str, usize)]) -> Result<usize> {
+    let mut hands: Vec<Hand> = input
+        .iter()
+        .map(|(hand, bet)| {
+            Ok(Hand {
+                hand: HandType::from_str(hand).map_err(Error::invalid)?,
+                bet: *bet,
+            })
+        })
+        .collect::<Result<_>>()?;
+    hands.sort_by(|x, y| x.hand.cmp(&y.hand));
+    total_winnings(hands.iter().map(|hand| hand.bet))
+}
+
+pub fn part2(input: &[(&str, usize)]) -> Result<usize> {
+    let mut hands: Vec<Hand2> = input
+        .iter()
+        .map(|(hand, bet)| {
+            Ok(Hand2 {
+                hand: HandType2::from_str(hand).map_err(Error::invalid)?,
+                bet: *bet,
+            })
+        })
+        .collect::<Result<_>>()?;
+    hands.sort_by(|x, y| x.hand.cmp(&y.hand));
+    total_winnings(hands.iter().map(|hand| hand.bet))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<(&'a str, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_hands(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<usize> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn hand_types() {
+        assert!(matches!(HandType::from_str("32T3K"), Ok(HandType::Pair(_))));
+        assert!(matches!(
+            HandType::from_str("KTJJT"),
+            Ok(HandType::TwoPair(_))
+        ));
+        assert!(HandType::from_str("KK677") > HandType::from_str("KTJJT"));
+        assert!(matches!(
+            HandType2::from_str("KTJJT"),
+            Ok(HandType2::Four(_))
+        ));
+        assert!(HandType2::from_str("KTJJT") > HandType2::from_str("QQQJA"));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input).unwrap(), 6440);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input).unwrap(), 5905);
+    }
+
+    #[test]
+    fn invalid_card_is_an_error() {
+        assert_eq!(
+            Day7::parse("32T3K 765\nT55X5 684\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 4: found 'X5', expected a card out of 23456789TJQKA"
+        );
+        assert!(HandType::from_str("32T3").is_err());
+    }
+
+    #[test]
+    fn winnings_that_overflow_are_an_error() {
+        let input = format!("32T3K 1\nKK677 {}\n", usize::MAX);
+        let input = Day7::parse(&input).unwrap();
+        assert_eq!(
+            Day7::part1(&input).unwrap_err().to_string(),
+            format!(
+                "invalid puzzle: the winnings of rank 2 do not fit into {} bits",
+                usize::BITS
+            )
+        );
+    }
+}
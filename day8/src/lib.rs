@@ -0,0 +1,205 @@
+//! Day 8: Haunted Wasteland. Following directions through a network of nodes.
+
+use std::{collections::HashMap, convert::Infallible, iter::Cycle, str::Chars};
+
+use common::{Error, Part, Result, Solution};
+use nom::{
+    bytes::complete::{tag, take_while},
+    combinator::opt,
+    IResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tag<'a>(pub &'a str);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Node<'a>(Tag<'a>, Tag<'a>);
+
+impl<'a> Node<'a> {
+    pub fn left(&'a self) -> Tag<'a> {
+        self.0
+    }
+    pub fn right(&'a self) -> Tag<'a> {
+        self.1
+    }
+}
+#[derive(Debug, Copy, Clone)]
+pub enum Direction {
+    L,
+    R,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Directions<'a>(&'a str);
+
+pub struct DirectionsIntoIter<'a>(Cycle<Chars<'a>>);
+
+impl<'a> IntoIterator for Directions<'a> {
+    type Item = Direction;
+
+    type IntoIter = DirectionsIntoIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DirectionsIntoIter(self.0.chars().cycle())
+    }
+}
+
+impl<'a> Iterator for DirectionsIntoIter<'a> {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.0.next() {
+            Some('L') => Some(Self::Item::L),
+            Some('R') => Some(Self::Item::R),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Directions<'a> {
+    fn from(value: &'a str) -> Self {
+        Self(value)
+    }
+}
+
+pub fn parse_tag(input: &str) -> IResult<&str, Tag<'_>> {
+    let (input, tag) = take_while(char::is_alphabetic)(input)?;
+    Ok((input, Tag(tag)))
+}
+
+/// Parses `AAA = (BBB, CCC)` and the line break after it.
+pub fn parse_node(input: &str) -> IResult<&str, (Tag<'_>, Node<'_>)> {
+    let (input, node_tag) = parse_tag(input)?;
+    let (input, _) = tag(" = (")(input)?;
+    let (input, left) = parse_tag(input)?;
+    let (input, _) = tag(", ")(input)?;
+    let (input, right) = parse_tag(input)?;
+    let (input, _) = tag(")")(input)?;
+    let (input, _) = opt(tag("\n"))(input)?;
+    Ok((input, (node_tag, Node(left, right))))
+}
+
+pub fn parse_map(input: &str) -> Result<(Directions<'_>, HashMap<Tag<'_>, Node<'_>>)> {
+    let Some((directions, mut nodes)) = input.split_once("\n\n") else {
+        return Err(Error::parse(
+            input,
+            &input[input.len()..],
+            "a blank line between the directions and the nodes",
+        ));
+    };
+    if let Some(index) = directions.find(|c| c != 'L' && c != 'R') {
+        return Err(Error::parse(
+            input,
+            &directions[index..],
+            "directions made of L and R",
+        ));
+    }
+    if directions.is_empty() {
+        return Err(Error::parse(input, directions, "at least one direction"));
+    }
+    let mut graph: HashMap<Tag, Node> = HashMap::new();
+    while !nodes.trim_end().is_empty() {
+        let (rest, (tag, node)) = parse_node(nodes)
+            .map_err(|err| Error::from_nom(input, err, "a node like `AAA = (BBB, CCC)`"))?;
+        graph.insert(tag, node);
+        nodes = rest;
+    }
+    Ok((Directions::from(directions), graph))
+}
+
+/// Counts the steps from `AAA` to `ZZZ`.
+pub fn part1(directions: Directions, graph: &HashMap<Tag, Node>) -> Result<usize> {
+    // Once every direction was taken from every node, the walk is going in circles.
+    let limit = directions.0.len().saturating_mul(graph.len());
+    let mut position = Tag("AAA");
+    let mut steps = 0;
+    for direction in directions {
+        if position == Tag("ZZZ") {
+            break;
+        }
+        if steps > limit {
+            return Err(Error::invalid("ZZZ cannot be reached from AAA"));
+        }
+        let node = graph
+            .get(&position)
+            .ok_or_else(|| Error::invalid(format!("node {} is not in the map", position.0)))?;
+        position = match direction {
+            Direction::L => node.left(),
+            Direction::R => node.right(),
+        };
+        steps += 1;
+    }
+    Ok(steps)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = (Directions<'a>, HashMap<Tag<'a>, Node<'a>>);
+    type Answer1 = usize;
+    type Answer2 = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_map(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize> {
+        part1(input.0, &input.1)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Infallible> {
+        Err(Error::Unsolved(Part::Two))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE2: &str = include_str!("../input.test");
+
+    #[test]
+    fn parses_nodes() {
+        let (rest, (tag, node)) = parse_node("AAA = (BBB, CCC)\n").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(tag, Tag("AAA"));
+        assert_eq!((node.left(), node.right()), (Tag("BBB"), Tag("CCC")));
+    }
+
+    #[test]
+    fn part1_examples() {
+        let input = Day8::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day8::part1(&input).unwrap(), 2);
+        let input = Day8::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day8::part1(&input).unwrap(), 6);
+    }
+
+    #[test]
+    fn bad_direction_is_an_error() {
+        assert_eq!(
+            Day8::parse("RLX\n\nAAA = (AAA, AAA)\n")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 3: found 'X', expected directions made of L and R"
+        );
+    }
+
+    #[test]
+    fn unreachable_end_is_an_error() {
+        let input =
+            Day8::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert!(matches!(Day8::part1(&input), Err(Error::Invalid(_))));
+    }
+}
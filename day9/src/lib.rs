@@ -0,0 +1,142 @@
+//! Day 9: Mirage Maintenance. Extrapolating sensor histories.
+
+use common::{Error, Result, Solution};
+
+/// Parses one history of numbers per line.
+pub fn read_input(input: &str) -> Result<Vec<Vec<i32>>> {
+    let lines: Vec<_> = input.lines().collect();
+    let mut hists = Vec::with_capacity(lines.len());
+    for line in lines {
+        if line.trim().is_empty() {
+            return Err(Error::parse(input, line, "a history of numbers"));
+        }
+        let nums = line
+            .split_whitespace()
+            .map(|x| str::parse::<i32>(x).map_err(|_| Error::parse(input, x, "a number")))
+            .collect::<Result<Vec<_>>>()?;
+        hists.push(nums);
+    }
+    Ok(hists)
+}
+
+/// The differences between neighbouring numbers, `None` if one does not fit
+/// into 32 bits.
+pub fn diffs(nums: &[i32]) -> Option<Vec<i32>> {
+    let mut diffs = Vec::with_capacity(nums.len().saturating_sub(1));
+    for n in nums.windows(2) {
+        let diff = n[1].checked_sub(n[0])?;
+        diffs.push(diff);
+    }
+    Some(diffs)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    L,
+    R,
+}
+
+/// Extrapolates the next (`R`) or previous (`L`) number of a history, `None`
+/// if it or any difference on the way does not fit into 32 bits.
+pub fn predict(nums: &[i32], direction: Direction) -> Option<i32> {
+    if nums.iter().all(|x| *x == 0) {
+        return Some(0);
+    }
+    let diffs = diffs(nums)?;
+    match direction {
+        Direction::L => nums[0].checked_sub(predict(&diffs, Direction::L)?),
+        Direction::R => predict(&diffs, Direction::R)?.checked_add(nums[nums.len() - 1]),
+    }
+}
+
+/// Sums the predictions of every history.
+pub fn sum_predictions(hists: &[Vec<i32>], direction: Direction) -> Result<i32> {
+    let mut sum = 0i32;
+    for (index, hist) in hists.iter().enumerate() {
+        sum = predict(hist, direction)
+            .and_then(|prediction| sum.checked_add(prediction))
+            .ok_or_else(|| {
+                Error::invalid(format!(
+                    "the prediction of history {} does not fit into 32 bits",
+                    index + 1
+                ))
+            })?;
+    }
+    Ok(sum)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        read_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i32> {
+        sum_predictions(input, Direction::R)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<i32> {
+        sum_predictions(input, Direction::L)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn predict_both_directions() {
+        let hists = Day9::parse(EXAMPLE).unwrap();
+        let right: Vec<_> = hists.iter().map(|h| predict(h, Direction::R)).collect();
+        let left: Vec<_> = hists.iter().map(|h| predict(h, Direction::L)).collect();
+        assert_eq!(right, [18, 28, 68].map(Some));
+        assert_eq!(left, [-3, 0, 5].map(Some));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input).unwrap(), 114);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn malformed_number_is_an_error() {
+        assert_eq!(
+            Day9::parse("0 3 6\n1 3x 6\n").unwrap_err().to_string(),
+            "line 2, column 3: found '3x', expected a number"
+        );
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        assert_eq!(diffs(&[2000000000, -2000000000]), None);
+        let input = Day9::parse("0 0\n2000000000 -2000000000\n").unwrap();
+        assert_eq!(
+            Day9::part1(&input).unwrap_err().to_string(),
+            "invalid puzzle: the prediction of history 2 does not fit into 32 bits"
+        );
+        let input = Day9::parse("2000000000 2000000000\n2000000000 2000000000\n").unwrap();
+        assert_eq!(
+            Day9::part2(&input).unwrap_err().to_string(),
+            "invalid puzzle: the prediction of history 2 does not fit into 32 bits"
+        );
+    }
+}